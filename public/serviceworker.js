@@ -14,6 +14,10 @@ var cachedURLs = [
   "/images/icon-512.png",
   "/images/remnant-logo.png",
   "/manifest.webmanifest",
+  "/pkg/snippets/remnant-68c4c8796a519e8d/src/js/download.js",
+  "/pkg/snippets/remnant-68c4c8796a519e8d/src/js/keyboard.js",
+  "/pkg/snippets/remnant-68c4c8796a519e8d/src/js/router.js",
+  "/pkg/snippets/remnant-68c4c8796a519e8d/src/js/scroll.js",
   "/pkg/snippets/remnant-68c4c8796a519e8d/src/js/share.js",
   "/pkg/snippets/remnant-68c4c8796a519e8d/src/js/stats.js",
 ];
@@ -1,31 +1,142 @@
+mod a11y;
+pub mod cli;
+mod compare;
+mod components;
 mod data;
+mod diff;
+mod facet;
+mod group;
+mod history;
+mod preset;
+mod router;
+mod search;
+mod sections;
+mod selection;
+mod shortcut;
+mod sort;
+mod stats;
 mod storage;
+mod theme;
+mod undo;
+mod window;
 
+use chrono::{DateTime, NaiveDate, Utc};
+use compare::{Comparison, Gap};
+use components::{EntryRow, Footer, Segments, WorldSelector};
 use data::{UrlParam, World};
-use storage::StorageService;
+use diff::{ProgressDiff, Side, Snapshot};
+use facet::WorldFacet;
+use group::{GroupSort, Member};
+use history::DateRange;
+use preset::Preset;
+use router::Route;
+use search::{highlight, Match, Query, SearchIndex};
+use sections::Grouping;
+use selection::Anchor;
+use serde_derive::{Deserialize, Serialize};
+use shortcut::Shortcut;
+use sort::SortOrder;
+use stats::Progress;
+use std::cell::RefCell;
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+use storage::{DataFormat, DefaultView, Preferences, Settings, StorageService, WikiProvider};
 use strum::IntoEnumIterator;
 use strum_macros::{AsRefStr, EnumIter};
+use theme::Theme;
+use undo::{Change, Edit, Mark, UndoStack};
 use wasm_bindgen::prelude::*;
+use window::{Viewport, Window};
 use yew::prelude::*;
+use yew::services::reader::{File, FileData, ReaderService, ReaderTask};
+use yew::services::timeout::{TimeoutService, TimeoutTask};
+use yew::services::Task;
+
+/// How long the search box waits for typing to pause before filtering.
+const SEARCH_DELAY: u64 = 150;
+
+/// How long the undo toast stays up after a change, in milliseconds.
+const TOAST_DELAY: u64 = 6000;
+
+/// Callbacks handed to child components. They are created once so props
+/// holding them compare equal from one render to the next.
+struct Callbacks {
+    clear_worlds: Callback<()>,
+    select_filter: Callback<Filter>,
+    toggle: Callback<(String, bool)>,
+    toggle_world: Callback<World>,
+}
 
 pub struct App {
+    _hash_change: Closure<dyn Fn(String)>,
+    _key_down: Closure<dyn Fn(String) -> bool>,
+    callbacks: Callbacks,
     link: ComponentLink<Self>,
+    reader: ReaderService,
+    reader_tasks: Vec<ReaderTask>,
+    scroll: Closure<dyn Fn(u32, u32)>,
+    search_task: Option<TimeoutTask>,
     storage: StorageService,
     state: State,
+    toast_task: Option<TimeoutTask>,
 }
 
 pub struct State {
+    /// Where a shift-click range starts.
+    anchor: Option<Anchor>,
+    announcement: String,
+    comparison: Option<Comparison>,
+    comparison_error: Option<String>,
+    /// A bulk change to the shown entries awaiting confirmation: whether
+    /// they will be marked complete.
+    confirm_mark: Option<bool>,
+    diff_after: Option<Snapshot>,
+    diff_before: Option<Snapshot>,
+    diff_error: Option<String>,
     entries: Vec<Entry>,
     filter: Filter,
+    focused: Option<String>,
+    group: Vec<Member>,
+    group_error: Option<String>,
+    group_sort: GroupSort,
+    history_range: DateRange,
+    page: Page,
+    preferences: Preferences,
+    preset_name: String,
+    presets: Vec<Preset>,
+    query: Query,
+    refocus: bool,
+    /// Row text already worked out under the current search and settings,
+    /// by entry id and progress, so a toggle only rebuilds its own row.
+    row_text: RefCell<HashMap<(String, bool), RowText>>,
     search: String,
-    world: World,
+    search_index: SearchIndex,
+    settings: Settings,
+    show_shortcuts: bool,
+    /// Whether the undo toast for the last change is showing.
+    toast: bool,
+    undo: UndoStack,
+    viewport: Viewport,
+    worlds: WorldFacet,
+}
+
+/// The searched-for text of a checklist row: its name and any other fields
+/// the search matched, with the matched words marked.
+#[derive(Clone)]
+struct RowText {
+    label: Segments,
+    matches: Vec<(String, Segments)>,
 }
 
 pub struct Entry {
     completed: bool,
+    completed_at: Option<DateTime<Utc>>,
     data_type: data::ItemType,
+    details: Vec<(data::Field, String)>,
     id: u32,
     name: String,
+    stats: Vec<(data::Stat, f64)>,
     url: String,
     worlds: Vec<World>,
 }
@@ -34,6 +145,38 @@ impl Entry {
     fn id(&self) -> String {
         format!("{}-{}", self.data_type.clone().url_slug(), self.id)
     }
+
+    const fn mark(&self) -> Mark {
+        Mark {
+            completed: self.completed,
+            completed_at: self.completed_at,
+        }
+    }
+
+    fn set_mark(&mut self, mark: &Mark) {
+        self.completed = mark.completed;
+        self.completed_at = mark.completed_at;
+    }
+}
+
+/// An incomplete ring that drops anywhere, like real data always listing
+/// `World::Any`, for tests to override with struct update syntax so
+/// fixtures only spell out the fields they care about.
+#[cfg(test)]
+impl Default for Entry {
+    fn default() -> Self {
+        Self {
+            completed: false,
+            completed_at: None,
+            data_type: data::ItemType::Ring,
+            details: vec![],
+            id: 1,
+            name: "Item".into(),
+            stats: vec![],
+            url: "https://example.com".into(),
+            worlds: vec![World::Any],
+        }
+    }
 }
 
 pub enum Goal {
@@ -44,12 +187,94 @@ pub enum Goal {
 }
 
 pub enum Msg {
+    ApplyPreset(usize),
+    ApplySearch,
+    CancelMarkVisible,
+    ClearComparison,
+    ClearItemTypes,
+    ClearWorlds,
+    ClearSnapshot(Side),
+    ComparisonLoaded(FileData),
+    ConfirmMarkVisible,
+    ExportProgress,
+    HideToast,
+    LoadComparison(File),
+    LoadMembers(Vec<File>),
+    LoadSnapshot(Side, File),
+    MarkVisible(bool),
+    MemberLoaded(FileData),
+    RemoveMember(usize),
+    Redo,
+    RemovePreset(usize),
+    RouteChanged(String),
+    SavePreset,
+    Scrolled(Viewport),
+    SnapshotLoaded(Side, FileData),
     SetFilter(Filter),
+    SetGroupSort(GroupSort),
+    SetGrouping(Grouping),
+    SetHistoryRange(DateRange),
+    SetPage(Page),
+    SetSettings(Settings),
+    SetSort(SortOrder),
     ShareApp(String),
+    Shortcut(Shortcut),
     Toggle(String),
+    ToggleGroup(String),
+    ToggleItemType(data::ItemType),
+    ToggleRange(String),
+    ToggleWorld(World),
     TrackGoal(Goal),
+    Undo,
+    UpdatePresetName(String),
     UpdateSearch(String),
-    UpdateWorld(World),
+}
+
+#[wasm_bindgen(module = "/src/js/download.js")]
+extern "C" {
+    #[allow(unsafe_code)]
+    fn download(filename: &str, contents: &str, mime_type: &str);
+}
+
+#[wasm_bindgen(module = "/src/js/keyboard.js")]
+extern "C" {
+    #[allow(unsafe_code)]
+    #[wasm_bindgen(js_name = focusElement)]
+    fn focus_element(id: &str) -> bool;
+
+    #[allow(unsafe_code)]
+    #[wasm_bindgen(js_name = onKeyDown)]
+    fn on_key_down(callback: &Closure<dyn Fn(String) -> bool>);
+}
+
+#[wasm_bindgen(module = "/src/js/router.js")]
+extern "C" {
+    #[allow(unsafe_code)]
+    #[wasm_bindgen(js_name = currentHash)]
+    fn current_hash() -> String;
+
+    #[allow(unsafe_code)]
+    #[wasm_bindgen(js_name = onHashChange)]
+    fn on_hash_change(callback: &Closure<dyn Fn(String)>);
+
+    #[allow(unsafe_code)]
+    #[wasm_bindgen(js_name = pushHash)]
+    fn push_hash(hash: &str);
+
+    #[allow(unsafe_code)]
+    #[wasm_bindgen(js_name = replaceHash)]
+    fn replace_hash(hash: &str);
+}
+
+#[wasm_bindgen(module = "/src/js/scroll.js")]
+extern "C" {
+    #[allow(unsafe_code)]
+    #[wasm_bindgen(js_name = onScroll)]
+    fn on_scroll(id: &str, callback: &Closure<dyn Fn(u32, u32)>);
+
+    #[allow(unsafe_code)]
+    #[wasm_bindgen(js_name = scrollToOffset)]
+    fn scroll_to_offset(id: &str, top: usize);
 }
 
 #[wasm_bindgen(module = "/src/js/share.js")]
@@ -64,6 +289,10 @@ extern "C" {
 
 #[wasm_bindgen(module = "/src/js/stats.js")]
 extern "C" {
+    #[allow(unsafe_code)]
+    #[wasm_bindgen(js_name = setTrackingEnabled)]
+    fn set_tracking_enabled(enabled: bool);
+
     #[allow(unsafe_code)]
     #[wasm_bindgen(js_name = markItemAsComplete)]
     fn track_mark_item_as_complete();
@@ -92,38 +321,368 @@ impl Component for App {
     fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
         let storage = StorageService::new().unwrap();
         let entries = storage.restore();
+        let preferences = storage.restore_preferences();
+        let presets = storage.restore_presets();
+        let settings = storage.restore_settings();
+        set_tracking_enabled(settings.analytics);
+
+        let callback = link.callback(Msg::RouteChanged);
+        let hash_change =
+            Closure::wrap(Box::new(move |hash| callback.emit(hash)) as Box<dyn Fn(String)>);
+        on_hash_change(&hash_change);
 
-        let state = State {
+        let callback = link.callback(Msg::Shortcut);
+        let key_down = Closure::wrap(Box::new(move |key: String| {
+            Shortcut::from_key(&key)
+                .map(|shortcut| callback.emit(shortcut))
+                .is_some()
+        }) as Box<dyn Fn(String) -> bool>);
+        on_key_down(&key_down);
+
+        let callback = link.callback(Msg::Scrolled);
+        let scroll = Closure::wrap(Box::new(move |top: u32, height: u32| {
+            callback.emit(Viewport {
+                height: height as usize,
+                top: top as usize,
+            });
+        }) as Box<dyn Fn(u32, u32)>);
+
+        let callbacks = Callbacks {
+            clear_worlds: link.callback(|_| Msg::ClearWorlds),
+            select_filter: link.callback(Msg::SetFilter),
+            toggle: link.callback(|(id, range)| {
+                if range {
+                    Msg::ToggleRange(id)
+                } else {
+                    Msg::Toggle(id)
+                }
+            }),
+            toggle_world: link.callback(Msg::ToggleWorld),
+        };
+
+        let search_index = SearchIndex::new(&entries);
+        let filter = preferences.filter.clone();
+        let page = preferences.page.clone();
+        let worlds = preferences.worlds.clone();
+        let mut state = State {
+            anchor: None,
+            announcement: "".into(),
+            comparison: None,
+            comparison_error: None,
+            confirm_mark: None,
+            diff_after: None,
+            diff_before: None,
+            diff_error: None,
             entries,
-            filter: Filter::Active,
+            filter,
+            focused: None,
+            group: vec![],
+            group_error: None,
+            group_sort: GroupSort::Lacking,
+            history_range: DateRange::default(),
+            page,
+            preferences,
+            preset_name: "".into(),
+            presets,
+            query: Query::default(),
+            refocus: false,
+            row_text: RefCell::default(),
             search: "".into(),
-            world: World::Any,
+            search_index,
+            settings,
+            show_shortcuts: false,
+            toast: false,
+            undo: UndoStack::default(),
+            viewport: Viewport::default(),
+            worlds,
         };
+        // A link decides the view; a bare visit opens the default view from
+        // settings, which is the last one used unless changed.
+        let hash = current_hash();
+        if hash.trim_start_matches('#').is_empty() {
+            if let Some(page) = state.settings.default_view.page() {
+                state.filter = Filter::default();
+                state.page = page;
+                state.worlds = WorldFacet::default();
+            }
+            replace_hash(&state.route().to_hash());
+        } else {
+            state.apply_route(Route::parse(&hash));
+        }
+
         Self {
+            _hash_change: hash_change,
+            _key_down: key_down,
+            callbacks,
             link,
+            reader: ReaderService::new(),
+            reader_tasks: vec![],
+            scroll,
+            search_task: None,
             storage,
             state,
+            toast_task: None,
         }
     }
 
+    #[allow(clippy::too_many_lines)]
     fn update(&mut self, msg: Self::Message) -> ShouldRender {
         match msg {
+            Msg::ApplyPreset(index) => {
+                if let Some(preset) = self.state.presets.get(index).cloned() {
+                    self.state.apply_preset(preset);
+                    self.push_view();
+                }
+                true
+            }
+            Msg::ApplySearch => {
+                self.search_task = None;
+                self.state.set_query(Query::new(&self.state.search));
+                replace_hash(&self.state.route().to_hash());
+                true
+            }
+            Msg::CancelMarkVisible => {
+                self.state.confirm_mark = None;
+                true
+            }
+            Msg::ClearComparison => {
+                self.state.comparison = None;
+                self.state.comparison_error = None;
+                true
+            }
+            Msg::ClearItemTypes => {
+                self.state.preferences.item_types.clear();
+                self.storage.store_preferences(&self.state.preferences);
+                true
+            }
+            Msg::ClearWorlds => {
+                self.state.worlds.clear();
+                self.push_view();
+                true
+            }
+            Msg::ClearSnapshot(side) => {
+                *self.state.snapshot_mut(side) = None;
+                true
+            }
+            Msg::ComparisonLoaded(file) => {
+                let label = file.name.clone();
+                match self.read_progress(&file) {
+                    Ok(data) => {
+                        self.state.comparison = Some(Comparison::new(label, data));
+                        self.state.comparison_error = None;
+                    }
+                    Err(error) => self.state.comparison_error = Some(error),
+                }
+                true
+            }
+            Msg::ConfirmMarkVisible => {
+                if let Some(completed) = self.state.confirm_mark.take() {
+                    let ids = self.state.visible_ids();
+                    self.mark(&ids, completed);
+                }
+                true
+            }
+            Msg::ExportProgress => {
+                let data = DataFormat::new(&self.state.entries);
+                download("remnant-progress.json", &data.to_json(), "application/json");
+                false
+            }
+            Msg::HideToast => {
+                self.state.toast = false;
+                self.toast_task = None;
+                true
+            }
+            Msg::LoadComparison(file) => {
+                let callback = self.link.callback(Msg::ComparisonLoaded);
+                self.state.comparison_error = self.read_file(file, callback).err();
+                true
+            }
+            Msg::LoadMembers(files) => {
+                self.state.group_error = None;
+                for file in files {
+                    let callback = self.link.callback(Msg::MemberLoaded);
+                    if let Err(error) = self.read_file(file, callback) {
+                        self.state.group_error = Some(error);
+                    }
+                }
+                true
+            }
+            Msg::LoadSnapshot(side, file) => {
+                let callback = self
+                    .link
+                    .callback(move |file| Msg::SnapshotLoaded(side, file));
+                self.state.diff_error = self.read_file(file, callback).err();
+                true
+            }
+            Msg::MarkVisible(completed) => {
+                self.state.confirm_mark = Some(completed);
+                true
+            }
+            Msg::MemberLoaded(file) => {
+                let name = file.name.clone();
+                match self.read_progress(&file) {
+                    Ok(data) => self.state.group.push(Member::new(name, data)),
+                    Err(error) => self.state.group_error = Some(error),
+                }
+                true
+            }
+            Msg::RemoveMember(index) => {
+                if index < self.state.group.len() {
+                    self.state.group.remove(index);
+                }
+                true
+            }
+            Msg::Redo => self.revert(false),
+            Msg::RemovePreset(index) => {
+                if index < self.state.presets.len() {
+                    self.state.presets.remove(index);
+                    self.storage.store_presets(&self.state.presets);
+                }
+                true
+            }
+            Msg::RouteChanged(hash) => {
+                self.state.apply_route(Route::parse(&hash));
+                self.remember_view();
+                true
+            }
+            Msg::SavePreset => {
+                let name = self.state.preset_name.trim().to_string();
+                if name.is_empty() {
+                    return false;
+                }
+                let preset = self.state.preset(name);
+                preset::save(&mut self.state.presets, preset);
+                self.state.preset_name.clear();
+                self.storage.store_presets(&self.state.presets);
+                true
+            }
+            Msg::Scrolled(viewport) => {
+                let changed = self.state.viewport.shows_other_rows(viewport);
+                self.state.viewport = viewport;
+                changed
+            }
             Msg::SetFilter(filter) => {
                 self.state.filter = filter;
+                self.state.announcement =
+                    a11y::filtered(self.state.filter.as_ref(), self.state.visible().len());
+                self.push_view();
+                true
+            }
+            Msg::SetGroupSort(sort) => {
+                self.state.group_sort = sort;
+                true
+            }
+            Msg::SetGrouping(grouping) => {
+                self.state.preferences.grouping = grouping;
+                self.storage.store_preferences(&self.state.preferences);
+                true
+            }
+            Msg::SetHistoryRange(range) => {
+                self.state.history_range = range;
+                true
+            }
+            Msg::SetPage(page) => {
+                self.state.page = page;
+                self.push_view();
+                true
+            }
+            Msg::SetSort(sort) => {
+                self.state.preferences.sort = sort;
+                self.storage.store_preferences(&self.state.preferences);
+                true
+            }
+            Msg::SetSettings(settings) => {
+                set_tracking_enabled(settings.analytics);
+                self.state.settings = settings;
+                self.state.row_text.get_mut().clear();
+                self.storage.store_settings(&self.state.settings);
                 true
             }
             Msg::ShareApp(url) => {
                 share(Some("Remnant Checklist".into()), None, url);
                 false
             }
+            Msg::Shortcut(shortcut) => self.handle_shortcut(shortcut),
+            Msg::SnapshotLoaded(side, file) => {
+                let label = file.name.clone();
+                match self.read_progress(&file) {
+                    Ok(data) => *self.state.snapshot_mut(side) = Some(Snapshot { data, label }),
+                    Err(error) => self.state.diff_error = Some(error),
+                }
+                true
+            }
             Msg::Toggle(id) => {
-                let goal = if self.state.toggle(&id) {
+                let before = self.state.visible_ids();
+                let edit = self.state.toggle(&id);
+                let completed = edit.after.completed;
+                let goal = if completed {
                     Goal::MarkItemAsComplete
                 } else {
                     Goal::MarkItemAsIncomplete
                 };
                 self.link.send_message(Msg::TrackGoal(goal));
-                self.storage.store(&self.state.entries);
+
+                let name = self
+                    .state
+                    .entries
+                    .iter()
+                    .find(|e| e.id() == id)
+                    .map(|e| e.name.clone())
+                    .unwrap_or_default();
+                self.record(Change {
+                    description: undo::describe(&name, completed),
+                    edits: vec![edit],
+                });
+                self.state.announcement =
+                    a11y::toggled(&name, completed, self.state.total_incomplete());
+                self.state.focused = a11y::refocus_target(&before, &self.state.visible_ids(), &id);
+                self.state.refocus = true;
+                self.state.anchor = Some(Anchor { id, shown: before });
+                true
+            }
+            Msg::ToggleRange(id) => {
+                let before = self.state.visible_ids();
+                let ids = self
+                    .state
+                    .anchor
+                    .as_ref()
+                    .map(|anchor| anchor.range(&id, &before))
+                    .unwrap_or_default();
+                if ids.is_empty() {
+                    return self.update(Msg::Toggle(id));
+                }
+
+                // The whole range follows the entry that was clicked.
+                let completed = self
+                    .state
+                    .entries
+                    .iter()
+                    .find(|e| e.id() == id)
+                    .map_or(false, |e| !e.completed);
+                self.mark(&ids, completed);
+                self.state.focused = a11y::refocus_target(&before, &self.state.visible_ids(), &id);
+                self.state.refocus = true;
+                self.state.anchor = Some(Anchor { id, shown: before });
+                true
+            }
+            Msg::ToggleGroup(key) => {
+                let collapsed = &mut self.state.preferences.collapsed;
+                if let Some(index) = collapsed.iter().position(|k| k == &key) {
+                    collapsed.remove(index);
+                } else {
+                    collapsed.push(key);
+                }
+                self.storage.store_preferences(&self.state.preferences);
+                true
+            }
+            Msg::ToggleItemType(item_type) => {
+                self.state.preferences.item_types.toggle(item_type);
+                self.storage.store_preferences(&self.state.preferences);
+                true
+            }
+            Msg::ToggleWorld(world) => {
+                self.state.worlds.toggle(world);
+                self.push_view();
                 true
             }
             Msg::TrackGoal(goal) => {
@@ -135,60 +694,90 @@ impl Component for App {
                 }
                 false
             }
-            Msg::UpdateSearch(value) => {
-                self.state.search = value;
+            Msg::Undo => self.revert(true),
+            Msg::UpdatePresetName(name) => {
+                self.state.preset_name = name;
                 true
             }
-            Msg::UpdateWorld(world) => {
-                self.state.world = world;
-                true
+            Msg::UpdateSearch(value) => {
+                self.state.search = value;
+                let callback = self.link.callback(|_| Msg::ApplySearch);
+                self.search_task = Some(TimeoutService::spawn(
+                    Duration::from_millis(SEARCH_DELAY),
+                    callback,
+                ));
+                false
             }
         }
     }
 
+    /// Puts keyboard focus on the focused entry once it is on screen, which
+    /// for a windowed-out entry takes a scroll and another render.
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            on_scroll("checklist", &self.scroll);
+        }
+        if self.state.refocus {
+            self.state.refocus = self
+                .state
+                .focused
+                .as_ref()
+                .map_or(false, |id| !self.state.focus_entry(id));
+        }
+    }
+
     fn view(&self) -> Html {
+        let theme = self.state.settings.theme.clone();
+        let shown = self.state.matching_progress();
+
         html! {
-            <div class="app-wrapper">
+            <div class=("app-wrapper", theme.class())>
+                <p aria-live="polite" class="visually-hidden" role="status">{ &self.state.announcement }</p>
+                { self.view_shortcuts() }
+                { self.view_toast() }
                 <section class="todoapp">
                     <header class="header">
                         <h1 class="logo-container"><img alt="Remnant logo" class="img-fluid logo" src="/images/remnant-logo.png" /></h1>
                         <div class="filter-fields">
                             <input
+                                aria-describedby="search-errors"
+                                aria-invalid=(!self.state.query.errors().is_empty()).to_string()
+                                aria-label="Search items"
                                 class="input-search"
+                                id="search"
                                 placeholder="Search..."
                                 oninput=self.link.callback(|e: InputData| Msg::UpdateSearch(e.value))
                                 type="text"
                                 value={self.state.search.clone()}
                             />
-                            <select class="input-world-select" onchange=self.link.callback(move |e| {
-                                if let ChangeData::Select(element) = e {
-                                    Msg::UpdateWorld(World::from_param(&element.value()).unwrap())
-                                } else {
-                                    unreachable!()
-                                }
-                            })>
-                                { for World::iter().map(|world| self.view_world(world)) }
-                            </select>
+                            { self.view_world_picker() }
+                            { self.view_sort_select() }
+                            { self.view_grouping_select() }
+                        </div>
+                        <div id="search-errors">
+                            { for self.state.query.errors().iter().map(|error| html! {
+                                <p class="error search-error">{ error }</p>
+                            }) }
                         </div>
+                        { self.view_item_type_facet() }
+                        { self.view_presets() }
+                        { self.view_bulk_actions() }
                     </header>
-                    <section class="main">
-                        <ul class="todo-list">
-                            { for self.state.entries.iter().filter(|e| self.state.filter.fit(e) && e.worlds.iter().any(|world| world == &self.state.world) && e.name.to_lowercase().contains(&self.state.search.to_lowercase()))
-                                .map(|val| self.view_entry(val)) }
-                        </ul>
+                    <section class="main" id="checklist">
+                        { self.view_page() }
                     </section>
-                    <footer class="footer">
-                        <span class="todo-count">
-                            <strong>{ self.state.total_incomplete() }</strong>
-                            { " item(s) left" }
-                        </span>
-                        <ul class="filters">
-                            { for Filter::iter().map(|flt| self.view_filter(&flt)) }
-                        </ul>
-                    </footer>
+                    <Footer
+                        filters=self.filter_links(&shown)
+                        on_select=self.callbacks.select_filter.clone()
+                        remaining=shown.remaining()
+                        selected=self.state.filter.clone()
+                        total_remaining=self.state.total_incomplete()
+                    />
                 </section>
                 <footer class="info">
                     <ul class="list-unstyled m0">
+                        <li class="pages">{ for Page::iter().map(|page| self.view_page_link(page)) }</li>
+                        <li>{ self.view_theme_toggle() }</li>
                         <li>{ "Created by " }<a href="https://coffee.dev" onclick=self.link.callback(|_| Msg::TrackGoal(Goal::VisitPersonalSite)) rel="noopener noreferrer" target="_blank">{ "Jonathan Knapp" }</a></li>
                         <li>
                             { "Game and artwork © " }
@@ -203,6 +792,699 @@ impl Component for App {
 }
 
 impl App {
+    /// Saves the page, filter and worlds as preferences, so the next visit
+    /// opens on the same view.
+    fn remember_view(&mut self) {
+        self.state.preferences.page = self.state.page.clone();
+        self.state.preferences.filter = self.state.filter.clone();
+        self.state.preferences.worlds = self.state.worlds.clone();
+        self.storage.store_preferences(&self.state.preferences);
+    }
+
+    fn push_view(&mut self) {
+        self.remember_view();
+        push_hash(&self.state.route().to_hash());
+    }
+
+    fn handle_shortcut(&mut self, shortcut: Shortcut) -> ShouldRender {
+        match shortcut {
+            Shortcut::CycleWorld => {
+                let next = shortcut::next_world(self.state.worlds.selected());
+                self.state.worlds = WorldFacet::new(next.into_iter().collect());
+                self.push_view();
+                true
+            }
+            Shortcut::Dismiss => {
+                let shown = self.state.show_shortcuts || self.state.confirm_mark.is_some();
+                self.state.confirm_mark = None;
+                self.state.show_shortcuts = false;
+                shown
+            }
+            Shortcut::FocusSearch => {
+                focus_element("search");
+                false
+            }
+            Shortcut::Next | Shortcut::Previous => {
+                if self.state.page != Page::Checklist {
+                    return false;
+                }
+                let visible = self.state.visible();
+                let current = self
+                    .state
+                    .focused
+                    .as_ref()
+                    .and_then(|id| visible.iter().position(|entry| &entry.id() == id));
+                let next = shortcut::step(current, shortcut == Shortcut::Next, visible.len())
+                    .map(|index| visible[index].id());
+                self.state.refocus = next.is_some();
+                self.state.focused = next;
+                true
+            }
+            Shortcut::SetFilter(filter) => {
+                self.link.send_message(Msg::SetFilter(filter));
+                false
+            }
+            Shortcut::Toggle => {
+                if self.state.page != Page::Checklist {
+                    return false;
+                }
+                match self.state.focused.clone() {
+                    Some(id) => self.update(Msg::Toggle(id)),
+                    None => false,
+                }
+            }
+            Shortcut::ToggleHelp => {
+                self.state.show_shortcuts = !self.state.show_shortcuts;
+                true
+            }
+            Shortcut::Redo => self.update(Msg::Redo),
+            Shortcut::Undo => self.update(Msg::Undo),
+        }
+    }
+
+    /// Marks several entries at once. However many change, progress is
+    /// saved, tracked and undone once.
+    fn mark(&mut self, ids: &[String], completed: bool) {
+        let edits = self.state.set_completed(ids, completed);
+        if edits.is_empty() {
+            return;
+        }
+
+        let goal = if completed {
+            Goal::MarkItemAsComplete
+        } else {
+            Goal::MarkItemAsIncomplete
+        };
+        self.link.send_message(Msg::TrackGoal(goal));
+
+        let changed = edits.len();
+        self.record(Change {
+            description: undo::describe(&format!("{} items", changed), completed),
+            edits,
+        });
+        self.state.announcement = a11y::marked(changed, completed, self.state.total_incomplete());
+    }
+
+    /// Saves a change to progress and briefly offers to undo it.
+    fn record(&mut self, change: Change) {
+        self.storage.store(&self.state.entries);
+        self.state.undo.push(change);
+        self.state.toast = true;
+        self.toast_task = Some(TimeoutService::spawn(
+            Duration::from_millis(TOAST_DELAY),
+            self.link.callback(|_| Msg::HideToast),
+        ));
+    }
+
+    /// Undoes the last change, or redoes the last undone one.
+    fn revert(&mut self, undo: bool) -> ShouldRender {
+        let change = if undo {
+            self.state.undo.undo().cloned()
+        } else {
+            self.state.undo.redo().cloned()
+        };
+        let change = match change {
+            Some(change) => change,
+            None => return false,
+        };
+
+        self.state.restore(&change, undo);
+        self.storage.store(&self.state.entries);
+        self.state.announcement =
+            a11y::reverted(&change.description, undo, self.state.total_incomplete());
+        self.state.toast = false;
+        self.toast_task = None;
+        true
+    }
+
+    /// Starts reading an uploaded file, keeping the task alive until the
+    /// callback fires.
+    fn read_file(&mut self, file: File, callback: Callback<FileData>) -> Result<(), String> {
+        let task = self
+            .reader
+            .read_file(file, callback)
+            .map_err(|error| error.to_string())?;
+        self.reader_tasks.push(task);
+        Ok(())
+    }
+
+    /// Parses an uploaded progress export once its read has finished.
+    fn read_progress(&mut self, file: &FileData) -> Result<DataFormat, String> {
+        self.reader_tasks.retain(Task::is_active);
+        let json = String::from_utf8_lossy(&file.content).into_owned();
+        DataFormat::from_json(json).map_err(|error| format!("{}: {}", file.name, error))
+    }
+
+    fn view_page(&self) -> Html {
+        match self.state.page {
+            Page::Checklist => self.view_checklist(),
+            Page::Compare => self.view_compare(),
+            Page::Group => self.view_group(),
+            Page::Diff => self.view_diff(),
+            Page::History => self.view_history(),
+            Page::Settings => self.view_settings(),
+            Page::Stats => self.view_stats(),
+        }
+    }
+
+    fn view_checklist(&self) -> Html {
+        let preferences = &self.state.preferences;
+        let entries = self.state.ordered();
+
+        if preferences.grouping == Grouping::Flat {
+            let entries: Vec<&Entry> = entries
+                .into_iter()
+                .filter(|e| self.state.filter.fit(e))
+                .collect();
+            let window = Window::new(self.state.viewport, entries.len());
+            return html! {
+                <ul aria-label="Checklist" class="todo-list" role="list">
+                    { Self::view_spacer(window.before) }
+                    { for entries[window.start..window.end].iter().map(|val| self.view_entry(val)) }
+                    { Self::view_spacer(window.after) }
+                </ul>
+            };
+        }
+
+        html! {
+            { for sections::group(&preferences.grouping, &entries, &self.state.worlds).iter().map(|group| self.view_entry_group(group)) }
+        }
+    }
+
+    /// Stands in for the rows a windowed list leaves unrendered.
+    fn view_spacer(height: usize) -> Html {
+        if height == 0 {
+            return html! {};
+        }
+
+        html! {
+            <li aria-hidden="true" class="spacer" style=format!("height: {}px", height)></li>
+        }
+    }
+
+    fn view_entry_group(&self, group: &sections::Group) -> Html {
+        let collapsed = self.state.preferences.collapsed.contains(&group.key);
+        let key = group.key.clone();
+        let total = group.entries.len().to_string();
+        let completed = group.completed().to_string();
+
+        html! {
+            <section class="entry-group">
+                <button aria-expanded={(!collapsed).to_string()} class="group-header" onclick=self.link.callback(move |_| Msg::ToggleGroup(key.clone())) type="button">
+                    <span class="group-title">{ if collapsed { "▸ " } else { "▾ " } }{ &group.label }</span>
+                    <span class="group-summary">{ group.summary() }</span>
+                    <progress class="group-progress" max=total value=completed></progress>
+                </button>
+                { if collapsed {
+                    html! {}
+                } else {
+                    html! {
+                        <ul aria-label=group.label.clone() class="todo-list" role="list">
+                            { for group.entries.iter().filter(|e| self.state.filter.fit(e)).map(|val| self.view_entry(val)) }
+                        </ul>
+                    }
+                } }
+            </section>
+        }
+    }
+
+    fn view_grouping_select(&self) -> Html {
+        let current = &self.state.preferences.grouping;
+        html! {
+            <select class="input-sort-select" title="Group by" onchange=self.link.callback(|e| {
+                if let ChangeData::Select(element) = e {
+                    Msg::SetGrouping(Grouping::from_param(&element.value()).unwrap_or_default())
+                } else {
+                    unreachable!()
+                }
+            })>
+                { for Grouping::iter().map(|grouping| html! {
+                    <option selected={&grouping == current} value={grouping.clone().url_slug()}>{ format!("Group: {}", grouping.as_ref()) }</option>
+                }) }
+            </select>
+        }
+    }
+
+    fn view_page_link(&self, page: Page) -> Html {
+        let title = page.as_ref().to_string();
+        let href = Route {
+            page: page.clone(),
+            ..self.state.route()
+        }
+        .to_hash();
+
+        html! {
+            <a class=if self.state.page == page { "selected" } else { "not-selected" }
+               href=href
+               onclick=self.link.callback(move |_| Msg::SetPage(page.clone()))>
+                { title }
+            </a>
+        }
+    }
+
+    fn view_compare(&self) -> Html {
+        let data = DataFormat::new(&self.state.entries);
+        let origin = yew::utils::window().location().origin().unwrap_or_default();
+        let link = compare::share_link(&origin, &data);
+
+        html! {
+            <div class="page compare">
+                <p>{ "Load a friend's exported progress to see which items you both still need." }</p>
+                <div class="compare-actions">
+                    <button class="btn" onclick=self.link.callback(|_| Msg::ExportProgress)>{ "Export my progress" }</button>
+                    <label class="btn">
+                        { "Load friend's progress" }
+                        <input accept=".json,application/json" class="input-file" type="file" onchange=self.link.callback(|e| {
+                            if let ChangeData::Files(files) = e {
+                                files.get(0).map_or(Msg::ClearComparison, Msg::LoadComparison)
+                            } else {
+                                unreachable!()
+                            }
+                        }) />
+                    </label>
+                    { self.view_share_link(link) }
+                </div>
+                { self.state.comparison_error.as_ref().map_or_else(|| html! {}, |error| html! {
+                    <p class="error">{ error }</p>
+                }) }
+                { self.state.comparison.as_ref().map_or_else(|| html! {}, |comparison| self.view_comparison(comparison)) }
+            </div>
+        }
+    }
+
+    fn view_share_link(&self, link: String) -> Html {
+        if can_share() {
+            html! {
+                <button class="btn" onclick=self.link.callback(move |_| Msg::ShareApp(link.clone()))>{ "Share my progress" }</button>
+            }
+        } else {
+            html! {
+                <input class="input-share-link" readonly=true title="Share link" type="text" value=link />
+            }
+        }
+    }
+
+    fn view_comparison(&self, comparison: &Comparison) -> Html {
+        let entries: Vec<&Entry> = self
+            .state
+            .entries
+            .iter()
+            .filter(|e| self.state.matches(e) && comparison.gap(e).is_some())
+            .collect();
+
+        html! {
+            <>
+                <div class="compare-header row">
+                    <strong>{ format!("Comparing with {}", comparison.label) }</strong>
+                    <button class="btn" onclick=self.link.callback(|_| Msg::ClearComparison)>{ "Clear" }</button>
+                </div>
+                <table class="compare-table">
+                    <thead>
+                        <tr>
+                            <th>{ "World" }</th>
+                            <th>{ "Both lack" }</th>
+                            <th>{ "Only you lack" }</th>
+                            <th>{ "Only friend lacks" }</th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        { for comparison.world_gaps(self.state.owned()).iter().map(|gaps| html! {
+                            <tr class=if self.state.worlds.is_selected(&gaps.world) { "selected" } else { "" }>
+                                <td>{ &gaps.world }</td>
+                                <td>{ gaps.shared }</td>
+                                <td>{ gaps.local }</td>
+                                <td>{ gaps.remote }</td>
+                            </tr>
+                        }) }
+                    </tbody>
+                </table>
+                <table class="compare-table">
+                    <thead>
+                        <tr>
+                            <th>{ "Item" }</th>
+                            <th>{ "You" }</th>
+                            <th>{ "Friend" }</th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        { for entries.iter().map(|entry| Self::view_comparison_row(comparison, entry)) }
+                    </tbody>
+                </table>
+            </>
+        }
+    }
+
+    fn view_comparison_row(comparison: &Comparison, entry: &Entry) -> Html {
+        let class = match comparison.gap(entry) {
+            Some(Gap::Shared) => "gap-shared",
+            _ => "gap-unshared",
+        };
+        let mark = |completed: bool| if completed { "✔" } else { "—" };
+
+        html! {
+            <tr class=class>
+                <td>
+                    { &entry.name }
+                    <span class="item-type">{ entry.data_type.to_string() }</span>
+                </td>
+                <td>{ mark(entry.completed) }</td>
+                <td>{ mark(comparison.has(entry)) }</td>
+            </tr>
+        }
+    }
+
+    fn view_group(&self) -> Html {
+        html! {
+            <div class="page group">
+                <p>{ "Load exported progress files from your group to see who still needs what." }</p>
+                <div class="compare-actions">
+                    <label class="btn">
+                        { "Add members' progress" }
+                        <input accept=".json,application/json" class="input-file" multiple=true type="file" onchange=self.link.callback(|e| {
+                            if let ChangeData::Files(files) = e {
+                                Msg::LoadMembers((0..files.length()).filter_map(|index| files.get(index)).collect())
+                            } else {
+                                unreachable!()
+                            }
+                        }) />
+                    </label>
+                    <label>
+                        { "Sort by " }
+                        <select onchange=self.link.callback(|e| {
+                            if let ChangeData::Select(element) = e {
+                                Msg::SetGroupSort(GroupSort::from_param(&element.value()).unwrap())
+                            } else {
+                                unreachable!()
+                            }
+                        })>
+                            { for GroupSort::iter().map(|sort| html! {
+                                <option selected={self.state.group_sort == sort} value=sort.clone().url_slug()>{ sort.as_ref() }</option>
+                            }) }
+                        </select>
+                    </label>
+                </div>
+                { self.state.group_error.as_ref().map_or_else(|| html! {}, |error| html! {
+                    <p class="error">{ error }</p>
+                }) }
+                { self.view_group_matrix() }
+            </div>
+        }
+    }
+
+    fn view_group_matrix(&self) -> Html {
+        let mut members = vec![Member::new(
+            "You".into(),
+            DataFormat::new(&self.state.entries),
+        )];
+        members.extend(self.state.group.iter().cloned());
+        let entries = group::sort(
+            &members,
+            self.state
+                .entries
+                .iter()
+                .filter(|e| self.state.matches(e))
+                .collect(),
+            &self.state.group_sort,
+        );
+        let total = self.state.owned().count();
+
+        html! {
+            <table class="compare-table group-table">
+                <thead>
+                    <tr>
+                        <th>{ "Item" }</th>
+                        { for members.iter().enumerate().map(|(index, member)| self.view_member_header(index, member)) }
+                        <th>{ "Lacking" }</th>
+                    </tr>
+                </thead>
+                <tbody>
+                    { for entries.iter().map(|entry| {
+                        let lacking = group::lacking(&members, entry);
+                        html! {
+                            <tr class=if lacking == members.len() { "nobody" } else { "" }>
+                                <td>
+                                    { &entry.name }
+                                    <span class="item-type">{ entry.data_type.to_string() }</span>
+                                </td>
+                                { for members.iter().map(|member| html! {
+                                    <td>{ if member.has(entry) { "✔" } else { "—" } }</td>
+                                }) }
+                                <td>{ lacking }</td>
+                            </tr>
+                        }
+                    }) }
+                </tbody>
+                <tfoot>
+                    <tr>
+                        <th>{ "Total" }</th>
+                        { for members.iter().map(|member| html! {
+                            <th>{ format!("{}/{}", member.total(self.state.owned()), total) }</th>
+                        }) }
+                        <th></th>
+                    </tr>
+                </tfoot>
+            </table>
+        }
+    }
+
+    fn view_member_header(&self, index: usize, member: &Member) -> Html {
+        if index == 0 {
+            return html! { <th>{ &member.name }</th> };
+        }
+
+        html! {
+            <th>
+                { &member.name }
+                <button class="btn-remove" title=format!("Remove {}", member.name) onclick=self.link.callback(move |_| Msg::RemoveMember(index - 1))>{ "×" }</button>
+            </th>
+        }
+    }
+
+    fn view_diff(&self) -> Html {
+        let current = DataFormat::new(&self.state.entries);
+        let after = self
+            .state
+            .diff_after
+            .as_ref()
+            .map_or(&current, |snapshot| &snapshot.data);
+
+        html! {
+            <div class="page diff">
+                <p>{ "Compare two progress exports to see what was gained and lost between them." }</p>
+                <div class="compare-actions">
+                    { self.view_snapshot_picker(Side::Before, "Before", "No file loaded") }
+                    { self.view_snapshot_picker(Side::After, "After", "Current progress") }
+                </div>
+                { self.state.diff_error.as_ref().map_or_else(|| html! {}, |error| html! {
+                    <p class="error">{ error }</p>
+                }) }
+                { self.state.diff_before.as_ref().map_or_else(|| html! {}, |before| {
+                    self.view_progress_diff(&ProgressDiff::new(&before.data.completed_items, &after.completed_items))
+                }) }
+            </div>
+        }
+    }
+
+    fn view_history(&self) -> Html {
+        let range = &self.state.history_range;
+        let points = history::cumulative(self.state.owned(), range);
+        let unlocked = history::unlocked(self.state.owned(), range);
+        let undated = history::undated(self.state.owned());
+
+        html! {
+            <div class="page history">
+                { self.view_history_range() }
+                { Self::view_history_chart(&points) }
+                <h3>{ "Recently unlocked" }</h3>
+                <ul class="history-feed list-unstyled">
+                    { for unlocked.iter().take(50).map(|entry| html! {
+                        <li>
+                            <time>{ entry.completed_at.map_or_else(String::new, |at| at.format("%Y-%m-%d %H:%M").to_string()) }</time>
+                            { &entry.name }
+                            <span class="item-type">{ entry.data_type.to_string() }</span>
+                        </li>
+                    }) }
+                </ul>
+                { if undated > 0 {
+                    html! { <p class="history-note">{ format!("{} item(s) were completed before completion dates were recorded.", undated) }</p> }
+                } else {
+                    html! {}
+                } }
+            </div>
+        }
+    }
+
+    fn view_history_range(&self) -> Html {
+        let range = &self.state.history_range;
+        let from_range = range.clone();
+        let to_range = range.clone();
+
+        html! {
+            <div class="history-range">
+                <label>
+                    { "From " }
+                    <input type="date" value=format_day(range.from) onchange=self.link.callback(move |e| {
+                        if let ChangeData::Value(value) = e {
+                            Msg::SetHistoryRange(DateRange { from: DateRange::parse_day(&value), ..from_range.clone() })
+                        } else {
+                            unreachable!()
+                        }
+                    }) />
+                </label>
+                <label>
+                    { "To " }
+                    <input type="date" value=format_day(range.to) onchange=self.link.callback(move |e| {
+                        if let ChangeData::Value(value) = e {
+                            Msg::SetHistoryRange(DateRange { to: DateRange::parse_day(&value), ..to_range.clone() })
+                        } else {
+                            unreachable!()
+                        }
+                    }) />
+                </label>
+                <button class="btn btn-link" onclick=self.link.callback(|_| Msg::SetHistoryRange(DateRange::default())) type="button">{ "All time" }</button>
+            </div>
+        }
+    }
+
+    fn view_history_chart(points: &[(NaiveDate, usize)]) -> Html {
+        let (first, last) = match (points.first(), points.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return html! { <p>{ "Nothing was unlocked in this range." }</p> },
+        };
+
+        html! {
+            <figure class="history-chart">
+                <svg preserveAspectRatio="none" viewBox="0 0 1000 400">
+                    <polyline points=history::chart_points(points) />
+                </svg>
+                <figcaption>
+                    <span>{ format_day(Some(first.0)) }</span>
+                    <span>{ format!("{} completed", last.1) }</span>
+                    <span>{ format_day(Some(last.0)) }</span>
+                </figcaption>
+            </figure>
+        }
+    }
+
+    fn view_stats(&self) -> Html {
+        let overall = Progress::new(self.state.owned());
+        let (anywhere, specific) = stats::any_world_split(self.state.owned());
+        let most_remaining = stats::most_remaining(self.state.owned(), 5);
+        let most = most_remaining.first().map_or(0, |(_, count)| *count);
+
+        html! {
+            <div class="page stats">
+                <h2>{ format!("{}% complete", overall.percent()) }</h2>
+                <p>{ format!("{} of {} items collected, {} to go.", overall.completed, overall.total, overall.remaining()) }</p>
+                { Self::view_bar(overall.percent()) }
+                <h3>{ "By item type" }</h3>
+                { Self::view_progress_chart(stats::by_type(self.state.owned()).into_iter().map(|(data_type, progress)| (data_type.to_string(), progress))) }
+                <h3>{ "By world" }</h3>
+                { Self::view_progress_chart(stats::by_world(self.state.owned()).into_iter().map(|(world, progress)| (world.to_string(), progress))) }
+                <h3>{ "Any world vs. world specific" }</h3>
+                { Self::view_progress_chart(vec![("Any world".to_string(), anywhere), ("World specific".to_string(), specific)].into_iter()) }
+                <h3>{ "Most remaining" }</h3>
+                <table class="stats-chart">
+                    { for most_remaining.into_iter().map(|(world, count)| html! {
+                        <tr>
+                            <th>{ world.to_string() }</th>
+                            <td class="stats-bar">{ Self::view_bar(count * 100 / most) }</td>
+                            <td>{ format!("{} left", count) }</td>
+                        </tr>
+                    }) }
+                </table>
+            </div>
+        }
+    }
+
+    fn view_progress_chart(rows: impl Iterator<Item = (String, Progress)>) -> Html {
+        html! {
+            <table class="stats-chart">
+                { for rows.map(|(label, progress)| html! {
+                    <tr>
+                        <th>{ label }</th>
+                        <td class="stats-bar">{ Self::view_bar(progress.percent()) }</td>
+                        <td>{ format!("{}/{}", progress.completed, progress.total) }</td>
+                    </tr>
+                }) }
+            </table>
+        }
+    }
+
+    fn view_bar(percent: usize) -> Html {
+        html! {
+            <svg class="bar" preserveAspectRatio="none" viewBox="0 0 100 10">
+                <rect class="bar-track" height="10" width="100" />
+                <rect class="bar-fill" height="10" width=percent.to_string() />
+            </svg>
+        }
+    }
+
+    fn view_snapshot_picker(&self, side: Side, title: &str, placeholder: &str) -> Html {
+        let snapshot = match side {
+            Side::Before => &self.state.diff_before,
+            Side::After => &self.state.diff_after,
+        };
+
+        html! {
+            <div class="snapshot-picker">
+                <strong>{ format!("{}: ", title) }</strong>
+                { snapshot.as_ref().map_or(placeholder, |snapshot| &snapshot.label) }
+                <label class="btn">
+                    { "Load file" }
+                    <input accept=".json,application/json" class="input-file" type="file" onchange=self.link.callback(move |e| {
+                        if let ChangeData::Files(files) = e {
+                            files.get(0).map_or(Msg::ClearSnapshot(side), |file| Msg::LoadSnapshot(side, file))
+                        } else {
+                            unreachable!()
+                        }
+                    }) />
+                </label>
+                { if snapshot.is_some() {
+                    html! { <button class="btn-remove" title="Clear" onclick=self.link.callback(move |_| Msg::ClearSnapshot(side))>{ "×" }</button> }
+                } else {
+                    html! {}
+                } }
+            </div>
+        }
+    }
+
+    fn view_progress_diff(&self, diff: &ProgressDiff) -> Html {
+        if diff.is_empty() {
+            return html! { <p>{ "No changes between these snapshots." }</p> };
+        }
+
+        html! {
+            <>
+                { self.view_diff_section("Gained", "diff-gained", &diff.gained) }
+                { self.view_diff_section("Lost", "diff-lost", &diff.lost) }
+            </>
+        }
+    }
+
+    fn view_diff_section(
+        &self,
+        title: &str,
+        class: &str,
+        items: &[storage::CompletedItem],
+    ) -> Html {
+        html! {
+            <section class=class>
+                <h2>{ format!("{} ({})", title, items.len()) }</h2>
+                { for diff::group_by_type(items).into_iter().map(|(data_type, items)| html! {
+                    <>
+                        <h3>{ data_type.to_string() }</h3>
+                        <ul class="list-unstyled">
+                            { for items.iter().map(|item| html! {
+                                <li>{ diff::name(&self.state.entries, item) }</li>
+                            }) }
+                        </ul>
+                    </>
+                }) }
+            </section>
+        }
+    }
+
     fn view_share(&self) -> Html {
         if can_share() {
             html! {
@@ -213,64 +1495,337 @@ impl App {
         }
     }
 
-    fn view_filter(&self, filter: &Filter) -> Html {
-        let flt = filter.clone();
+    fn view_item_type_facet(&self) -> Html {
+        let counts = facet::counts(
+            self.state
+                .entries
+                .iter()
+                .filter(|e| self.state.filter.fit(e) && self.state.matches_except_type(e)),
+        );
 
         html! {
-            <li>
-                <a class=if self.state.filter == flt { "selected" } else { "not-selected" }
-                   href=&flt
-                   onclick=self.link.callback(move |_| Msg::SetFilter(flt.clone()))>
-                    { filter.as_ref() }
-                </a>
-            </li>
+            <div class="type-facet">
+                { for counts.into_iter().map(|(item_type, count)| {
+                    let selected = self.state.preferences.item_types.is_selected(&item_type);
+                    let title = format!("{} ({})", item_type, count);
+                    html! {
+                        <label class=if selected { "facet selected" } else { "facet" }>
+                            <input checked=selected type="checkbox" onclick=self.link.callback(move |_| Msg::ToggleItemType(item_type.clone())) />
+                            { title }
+                        </label>
+                    }
+                }) }
+                { if self.state.preferences.item_types.is_empty() {
+                    html! {}
+                } else {
+                    html! { <button class="btn-link" onclick=self.link.callback(|_| Msg::ClearItemTypes)>{ "All types" }</button> }
+                } }
+            </div>
         }
     }
 
-    fn view_entry(&self, entry: &Entry) -> Html {
-        let mut class = "todo".to_string();
-        if entry.completed {
-            class.push_str(" completed");
+    fn view_presets(&self) -> Html {
+        html! {
+            <div class="presets">
+                { for self.state.presets.iter().enumerate().map(|(index, preset)| html! {
+                    <span class="preset">
+                        <button class="btn-link" onclick=self.link.callback(move |_| Msg::ApplyPreset(index)) title=preset.describe()>
+                            { &preset.name }
+                        </button>
+                        <button class="btn-remove" onclick=self.link.callback(move |_| Msg::RemovePreset(index)) title="Delete preset">{ "×" }</button>
+                    </span>
+                }) }
+                <form class="preset-form" onsubmit=self.link.callback(|e: FocusEvent| { e.prevent_default(); Msg::SavePreset })>
+                    <input
+                        class="input-share-link"
+                        oninput=self.link.callback(|e: InputData| Msg::UpdatePresetName(e.value))
+                        placeholder="Preset name"
+                        type="text"
+                        value={self.state.preset_name.clone()}
+                    />
+                    <button class="btn" disabled=self.state.preset_name.trim().is_empty() type="submit">{ "Save view" }</button>
+                </form>
+            </div>
         }
+    }
+
+    fn view_bulk_actions(&self) -> Html {
+        if self.state.page != Page::Checklist {
+            return html! {};
+        }
+
+        let shown = self.state.visible();
+        if let Some(completed) = self.state.confirm_mark {
+            let count = shown.iter().filter(|e| e.completed != completed).count();
+            return html! {
+                <div class="bulk-actions" role="alert">
+                    <span>{ format!("Mark {} shown item(s) {}?", count, a11y::state_word(completed)) }</span>
+                    <button class="btn" onclick=self.link.callback(|_| Msg::ConfirmMarkVisible) type="button">{ "Confirm" }</button>
+                    <button class="btn-link" onclick=self.link.callback(|_| Msg::CancelMarkVisible) type="button">{ "Cancel" }</button>
+                </div>
+            };
+        }
+
+        html! {
+            <div class="bulk-actions">
+                <button class="btn-link" disabled=shown.iter().all(|e| e.completed) onclick=self.link.callback(|_| Msg::MarkVisible(true)) type="button">
+                    { "Mark shown complete" }
+                </button>
+                <button class="btn-link" disabled=shown.iter().all(|e| !e.completed) onclick=self.link.callback(|_| Msg::MarkVisible(false)) type="button">
+                    { "Mark shown incomplete" }
+                </button>
+            </div>
+        }
+    }
+
+    fn view_entry(&self, entry: &Entry) -> Html {
         let id = entry.id();
+        let settings = &self.state.settings;
+        let text = self.state.row_text(entry);
 
         html! {
-            <li class=class>
-                <div class="row view">
-                    <div class="row-label">
-                        <input class="toggle" id=entry.id() type="checkbox" checked={entry.completed} onclick=self.link.callback(move |_| Msg::Toggle(id.clone())) />
-                        <label class="item-label" for=entry.id()>
-                            { entry.name.to_string() }
-                            <span class="item-type">{ entry.data_type.to_string() }</span>
-                        </label>
-                    </div>
-                    <a class="wiki-link" href={ entry.url.clone() } rel="noopener noreferrer" target="_blank" title={format!("View {} on fextralife wiki", &entry.name)}>{ "wiki ↱" }</a>
+            <EntryRow
+                completed=entry.completed
+                focused={ self.state.focused.as_ref() == Some(&id) }
+                id=id.clone()
+                item_type=entry.data_type.to_string()
+                key=id
+                label=text.label
+                matches=text.matches
+                name=entry.name.clone()
+                on_toggle=self.callbacks.toggle.clone()
+                url=settings.wiki.url(entry)
+                wiki=settings.wiki.as_ref().to_string()
+            />
+        }
+    }
+
+    /// Every filter tab with the link that selects it and how many of the
+    /// `shown` entries it would list.
+    fn filter_links(&self, shown: &Progress) -> Vec<(Filter, String, usize)> {
+        let route = self.state.route();
+        Filter::iter()
+            .map(|filter| {
+                let href = Route {
+                    filter: Some(filter.clone()),
+                    page: Page::Checklist,
+                    ..route.clone()
+                }
+                .to_hash();
+                let count = match filter {
+                    Filter::All => shown.total,
+                    Filter::Active => shown.remaining(),
+                    Filter::Completed => shown.completed,
+                };
+                (filter, href, count)
+            })
+            .collect()
+    }
+
+    fn view_settings(&self) -> Html {
+        let settings = &self.state.settings;
+
+        html! {
+            <div class="page settings">
+                <h2>{ "Settings" }</h2>
+                { self.view_setting_select(
+                    "Theme",
+                    Theme::iter().map(|theme| (theme.slug(), theme.as_ref().to_string())).collect(),
+                    settings.theme.slug(),
+                    |settings, value| settings.theme = Theme::from_param(value).unwrap_or_default(),
+                ) }
+                { self.view_setting_select(
+                    "Wiki links",
+                    WikiProvider::iter().map(|wiki| (wiki.clone().url_slug(), wiki.as_ref().to_string())).collect(),
+                    settings.wiki.clone().url_slug(),
+                    |settings, value| settings.wiki = WikiProvider::from_param(value).unwrap_or_default(),
+                ) }
+                { self.view_setting_select(
+                    "Open on",
+                    DefaultView::iter().map(|view| (view.clone().url_slug(), view.as_ref().to_string())).collect(),
+                    settings.default_view.clone().url_slug(),
+                    |settings, value| settings.default_view = DefaultView::from_param(value).unwrap_or_default(),
+                ) }
+                { self.view_setting_toggle(
+                    "I own the Subject 2923 DLC",
+                    settings.subject_2923,
+                    |settings, checked| settings.subject_2923 = checked,
+                ) }
+                { self.view_setting_toggle(
+                    "Hide descriptions and locations of items I have not found",
+                    settings.hide_spoilers,
+                    |settings, checked| settings.hide_spoilers = checked,
+                ) }
+                { self.view_setting_toggle(
+                    "Send anonymous usage statistics",
+                    settings.analytics,
+                    |settings, checked| settings.analytics = checked,
+                ) }
+                <button class="btn" onclick=self.link.callback(|_| Msg::SetSettings(Settings::default())) type="button">{ "Reset to defaults" }</button>
+            </div>
+        }
+    }
+
+    /// A labelled select whose choice is written into the settings by
+    /// `apply`.
+    fn view_setting_select(
+        &self,
+        label: &str,
+        options: Vec<(&'static str, String)>,
+        current: &str,
+        apply: fn(&mut Settings, &str),
+    ) -> Html {
+        let settings = self.state.settings.clone();
+
+        html! {
+            <label class="setting">
+                <span>{ label }</span>
+                <select class="input-sort-select" onchange=self.link.callback(move |e| {
+                    if let ChangeData::Select(element) = e {
+                        let mut settings = settings.clone();
+                        apply(&mut settings, &element.value());
+                        Msg::SetSettings(settings)
+                    } else {
+                        unreachable!()
+                    }
+                })>
+                    { for options.into_iter().map(|(value, title)| html! {
+                        <option selected={value == current} value=value>{ title }</option>
+                    }) }
+                </select>
+            </label>
+        }
+    }
+
+    fn view_setting_toggle(
+        &self,
+        label: &str,
+        checked: bool,
+        apply: fn(&mut Settings, bool),
+    ) -> Html {
+        let mut settings = self.state.settings.clone();
+        apply(&mut settings, !checked);
+
+        html! {
+            <label class="setting setting-toggle">
+                <input checked=checked onclick=self.link.callback(move |_| Msg::SetSettings(settings.clone())) type="checkbox" />
+                <span>{ label }</span>
+            </label>
+        }
+    }
+
+    fn view_toast(&self) -> Html {
+        match self.state.undo.last() {
+            Some(change) if self.state.toast => html! {
+                <div class="toast">
+                    <span>{ &change.description }</span>
+                    <button class="btn-link" onclick=self.link.callback(|_| Msg::Undo) type="button">{ "Undo" }</button>
                 </div>
-            </li>
+            },
+            _ => html! {},
         }
     }
 
-    fn view_world(&self, world: World) -> Html {
+    fn view_theme_toggle(&self) -> Html {
+        let theme = &self.state.settings.theme;
+        let mut next = self.state.settings.clone();
+        next.theme = theme.next();
+
         html! {
-            <option selected={self.state.world == world} value=world.clone().url_slug()>{ world }</option>
+            <button class="btn-link" onclick=self.link.callback(move |_| Msg::SetSettings(next.clone())) title="Switch theme" type="button">
+                { format!("Theme: {}", theme.as_ref()) }
+            </button>
         }
     }
+
+    fn view_shortcuts(&self) -> Html {
+        if !self.state.show_shortcuts {
+            return html! {};
+        }
+
+        html! {
+            <div aria-label="Keyboard shortcuts" aria-modal="true" class="shortcuts" role="dialog">
+                <h2>{ "Keyboard shortcuts" }</h2>
+                <table class="compare-table">
+                    { for shortcut::HELP.iter().map(|(key, action)| html! {
+                        <tr><th><kbd>{ key }</kbd></th><td>{ action }</td></tr>
+                    }) }
+                </table>
+                <button class="btn" onclick=self.link.callback(|_| Msg::Shortcut(Shortcut::Dismiss))>{ "Close" }</button>
+            </div>
+        }
+    }
+
+    fn view_sort_select(&self) -> Html {
+        let current = &self.state.preferences.sort;
+        html! {
+            <select class="input-sort-select" title="Sort by" onchange=self.link.callback(|e| {
+                if let ChangeData::Select(element) = e {
+                    Msg::SetSort(SortOrder::from_param(&element.value()).unwrap_or_default())
+                } else {
+                    unreachable!()
+                }
+            })>
+                { for SortOrder::options().into_iter().map(|order| html! {
+                    <option selected={&order == current} value={order.to_param()}>{ format!("Sort: {}", order) }</option>
+                }) }
+            </select>
+        }
+    }
+
+    fn view_world_picker(&self) -> Html {
+        let remaining = facet::remaining_by_world(
+            self.state
+                .entries
+                .iter()
+                .filter(|e| self.state.matches_except_world(e)),
+        );
+        let total: usize = self
+            .state
+            .entries
+            .iter()
+            .filter(|e| !e.completed && self.state.matches_except_world(e))
+            .count();
+
+        html! {
+            <WorldSelector
+                on_clear=self.callbacks.clear_worlds.clone()
+                on_toggle=self.callbacks.toggle_world.clone()
+                remaining=remaining
+                selected=self.state.worlds.selected().to_vec()
+                total=total
+            />
+        }
+    }
+}
+
+/// Formats a day the way `<input type="date">` expects, leaving it empty
+/// when unset.
+fn format_day(day: Option<NaiveDate>) -> String {
+    day.map_or_else(String::new, |day| day.format("%Y-%m-%d").to_string())
 }
 
-#[derive(AsRefStr, Clone, EnumIter, PartialEq)]
+#[derive(AsRefStr, Clone, Debug, Deserialize, EnumIter, PartialEq, Serialize)]
+pub enum Page {
+    Checklist,
+    Compare,
+    Group,
+    Diff,
+    History,
+    Stats,
+    Settings,
+}
+
+#[derive(AsRefStr, Clone, Debug, Deserialize, EnumIter, PartialEq, Serialize)]
 pub enum Filter {
     All,
     Active,
     Completed,
 }
 
-impl<'a> Into<Href> for &'a Filter {
-    fn into(self) -> Href {
-        match self {
-            Filter::All => "#/".into(),
-            Filter::Active => "#/active".into(),
-            Filter::Completed => "#/completed".into(),
-        }
+impl Default for Filter {
+    fn default() -> Self {
+        Self::Active
     }
 }
 
@@ -285,16 +1840,251 @@ impl Filter {
 }
 
 impl State {
-    fn total_incomplete(&self) -> usize {
+    fn apply_route(&mut self, route: Route) {
+        if let Some(filter) = route.filter {
+            self.filter = filter;
+        }
+        if let Some(progress) = route.progress {
+            self.comparison = Some(Comparison::from_share_param(&progress));
+        }
+        self.page = route.page;
+        self.set_search(route.search);
+        self.worlds = WorldFacet::new(route.worlds);
+    }
+
+    fn apply_preset(&mut self, preset: Preset) {
+        self.filter = preset.filter;
+        self.page = Page::Checklist;
+        self.preferences.item_types = preset.item_types;
+        self.set_search(preset.search);
+        self.worlds = preset.worlds;
+    }
+
+    /// Captures the current checklist view under `name`.
+    fn preset(&self, name: String) -> Preset {
+        Preset {
+            filter: self.filter.clone(),
+            item_types: self.preferences.item_types.clone(),
+            name,
+            search: self.search.clone(),
+            worlds: self.worlds.clone(),
+        }
+    }
+
+    /// Progress over every entry matching the current view whatever the
+    /// completion filter, counted in one pass for the filter tabs.
+    fn matching_progress(&self) -> Progress {
+        Progress::new(self.entries.iter().filter(|entry| self.matches(entry)))
+    }
+
+    /// Matching entries in the order the checklist shows them, before the
+    /// completion filter and collapsed groups are applied.
+    fn ordered(&self) -> Vec<&Entry> {
+        self.rank(
+            self.preferences
+                .sort
+                .sort(self.entries.iter().filter(|e| self.matches(e)).collect()),
+        )
+    }
+
+    /// Focuses an entry's checkbox, or scrolls the windowed list to bring
+    /// the entry into the DOM when it is not rendered yet.
+    fn focus_entry(&self, id: &str) -> bool {
+        if focus_element(id) {
+            return true;
+        }
+        if let Some(index) = self.visible_ids().iter().position(|shown| shown == id) {
+            scroll_to_offset("checklist", Window::offset(index));
+        }
+        false
+    }
+
+    fn visible_ids(&self) -> Vec<String> {
+        self.visible().iter().map(|entry| entry.id()).collect()
+    }
+
+    /// The entries currently on screen, top to bottom.
+    fn visible(&self) -> Vec<&Entry> {
+        let entries = self.ordered();
+        let shown: Vec<&Entry> = if self.preferences.grouping == Grouping::Flat {
+            entries
+        } else {
+            sections::group(&self.preferences.grouping, &entries, &self.worlds)
+                .into_iter()
+                .filter(|group| !self.preferences.collapsed.contains(&group.key))
+                .flat_map(|group| group.entries)
+                .collect()
+        };
+        shown
+            .into_iter()
+            .filter(|entry| self.filter.fit(entry))
+            .collect()
+    }
+
+    fn route(&self) -> Route {
+        Route {
+            filter: Some(self.filter.clone()),
+            page: self.page.clone(),
+            progress: None,
+            search: self.search.clone(),
+            worlds: self.worlds.selected().to_vec(),
+        }
+    }
+
+    fn snapshot_mut(&mut self, side: Side) -> &mut Option<Snapshot> {
+        match side {
+            Side::Before => &mut self.diff_before,
+            Side::After => &mut self.diff_after,
+        }
+    }
+
+    /// Whether an entry fits the selected item types, worlds and search text.
+    fn matches(&self, entry: &Entry) -> bool {
+        self.preferences.item_types.fit(entry) && self.matches_except_type(entry)
+    }
+
+    fn matches_except_type(&self, entry: &Entry) -> bool {
+        self.settings.owns(entry) && self.worlds.fit(entry) && self.matches_search(entry)
+    }
+
+    fn matches_except_world(&self, entry: &Entry) -> bool {
+        self.settings.owns(entry)
+            && self.preferences.item_types.fit(entry)
+            && self.matches_search(entry)
+    }
+
+    fn matches_search(&self, entry: &Entry) -> bool {
+        self.search_index.score(entry, &self.query).is_some()
+    }
+
+    /// Puts the closest search matches first, keeping the chosen sort order
+    /// among equally good matches. Each entry is scored once.
+    fn rank<'a>(&self, mut entries: Vec<&'a Entry>) -> Vec<&'a Entry> {
+        if !self.query.is_empty() {
+            entries
+                .sort_by_cached_key(|entry| Reverse(self.search_index.score(entry, &self.query)));
+        }
+        entries
+    }
+
+    /// The text of a secondary field a search matched.
+    fn match_text(&self, entry: &Entry, field: data::Field) -> String {
+        if field == data::Field::Alias {
+            self.search_index.aliases(entry).join(", ")
+        } else {
+            entry
+                .details
+                .iter()
+                .find(|(detail, _)| *detail == field)
+                .map_or_else(String::new, |(_, text)| text.clone())
+        }
+    }
+
+    fn search_match(&self, entry: &Entry) -> Option<Match> {
+        if self.query.is_empty() {
+            None
+        } else {
+            self.search_index.search(entry, &self.query)
+        }
+    }
+
+    fn set_query(&mut self, query: Query) {
+        self.query = query;
+        self.row_text.get_mut().clear();
+    }
+
+    fn set_search(&mut self, search: String) {
+        self.set_query(Query::new(&search));
+        self.search = search;
+    }
+
+    /// An entry's highlighted name and the fields the search matched,
+    /// reused until the search or settings change.
+    fn row_text(&self, entry: &Entry) -> RowText {
+        let key = (entry.id(), entry.completed);
+        if let Some(text) = self.row_text.borrow().get(&key) {
+            return text.clone();
+        }
+
+        let matches = self
+            .search_match(entry)
+            .filter(|_| self.settings.reveals(entry))
+            .map(|found| found.fields)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|field| {
+                let text = self.match_text(entry, field);
+                (
+                    field.to_string(),
+                    highlight(&text, &self.query, &self.search_index),
+                )
+            })
+            .collect();
+        let text = RowText {
+            label: highlight(&entry.name, &self.query, &self.search_index),
+            matches,
+        };
+        self.row_text.borrow_mut().insert(key, text.clone());
+        text
+    }
+
+    /// Entries from the content the user owns, which every progress count
+    /// is taken over.
+    fn owned(&self) -> impl Iterator<Item = &Entry> + Clone {
         self.entries
             .iter()
-            .map(|x| if x.completed { 0 } else { 1 })
-            .sum()
+            .filter(move |entry| self.settings.owns(entry))
     }
 
-    fn toggle(&mut self, id: &str) -> bool {
-        let mut entry = self.entries.iter_mut().find(|x| x.id() == id).unwrap();
+    fn total_incomplete(&self) -> usize {
+        self.owned().map(|x| if x.completed { 0 } else { 1 }).sum()
+    }
+
+    /// Puts entries back to how they were before a change, or how they
+    /// were after it.
+    fn restore(&mut self, change: &Change, before: bool) {
+        for edit in &change.edits {
+            if let Some(entry) = self.entries.iter_mut().find(|e| e.id() == edit.id) {
+                entry.set_mark(if before { &edit.before } else { &edit.after });
+            }
+        }
+    }
+
+    /// Marks the given entries complete or incomplete, returning what
+    /// changed.
+    fn set_completed(&mut self, ids: &[String], completed: bool) -> Vec<Edit> {
+        let ids: HashSet<&String> = ids.iter().collect();
+        let after = Mark {
+            completed,
+            completed_at: if completed { Some(Utc::now()) } else { None },
+        };
+        let mut edits = vec![];
+        for entry in &mut self.entries {
+            if entry.completed != completed && ids.contains(&entry.id()) {
+                edits.push(Edit {
+                    after: after.clone(),
+                    before: entry.mark(),
+                    id: entry.id(),
+                });
+                entry.set_mark(&after);
+            }
+        }
+        edits
+    }
+
+    fn toggle(&mut self, id: &str) -> Edit {
+        let entry = self.entries.iter_mut().find(|x| x.id() == id).unwrap();
+        let before = entry.mark();
         entry.completed = !entry.completed;
-        entry.completed
+        entry.completed_at = if entry.completed {
+            Some(Utc::now())
+        } else {
+            None
+        };
+        Edit {
+            after: entry.mark(),
+            before,
+            id: id.into(),
+        }
     }
 }
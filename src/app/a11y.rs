@@ -0,0 +1,100 @@
+/// How an entry's progress reads in messages, e.g. "marked complete".
+pub const fn state_word(completed: bool) -> &'static str {
+    if completed {
+        "complete"
+    } else {
+        "incomplete"
+    }
+}
+
+/// What screen readers hear after an item is toggled, e.g.
+/// "Akari Mask marked complete, 212 left".
+pub fn toggled(name: &str, completed: bool, left: usize) -> String {
+    format!("{} marked {}, {} left", name, state_word(completed), left)
+}
+
+/// What screen readers hear after several items change at once, e.g.
+/// "12 items marked complete, 201 left".
+pub fn marked(count: usize, completed: bool, left: usize) -> String {
+    format!(
+        "{} items marked {}, {} left",
+        count,
+        state_word(completed),
+        left
+    )
+}
+
+/// What screen readers hear after undoing or redoing a change, e.g.
+/// "Undid Akari Mask marked complete, 213 left".
+pub fn reverted(description: &str, undo: bool, left: usize) -> String {
+    let action = if undo { "Undid" } else { "Redid" };
+    format!("{} {}, {} left", action, description, left)
+}
+
+/// What screen readers hear after switching filters, e.g.
+/// "Showing active items, 212 shown".
+pub fn filtered(filter: &str, shown: usize) -> String {
+    format!("Showing {} items, {} shown", filter.to_lowercase(), shown)
+}
+
+/// Where keyboard focus belongs once a re-render has moved entries around:
+/// the entry itself while it is still shown, otherwise the nearest entry
+/// that followed it, or failing that the nearest one before it.
+pub fn refocus_target(before: &[String], after: &[String], id: &str) -> Option<String> {
+    if after.iter().any(|shown| shown == id) {
+        return Some(id.into());
+    }
+
+    let index = before.iter().position(|shown| shown == id)?;
+    before[index + 1..]
+        .iter()
+        .chain(before[..index].iter().rev())
+        .find(|candidate| after.contains(candidate))
+        .cloned()
+}
+
+#[cfg(test)]
+#[allow(clippy::wildcard_imports)]
+mod tests {
+    use super::*;
+
+    fn ids(ids: &[&str]) -> Vec<String> {
+        ids.iter().map(|id| id.to_string()).collect()
+    }
+
+    #[test]
+    fn announcements_describe_the_change() {
+        assert_eq!(
+            "Akari Mask marked complete, 212 left",
+            toggled("Akari Mask", true, 212)
+        );
+        assert_eq!("12 items marked complete, 201 left", marked(12, true, 201));
+        assert_eq!(
+            "Undid Akari Mask marked complete, 213 left",
+            reverted("Akari Mask marked complete", true, 213)
+        );
+        assert_eq!("Showing active items, 3 shown", filtered("Active", 3));
+    }
+
+    #[test]
+    fn focus_stays_on_an_entry_that_is_still_shown() {
+        let shown = ids(&["a", "b", "c"]);
+
+        assert_eq!(Some("b".into()), refocus_target(&shown, &shown, "b"));
+    }
+
+    #[test]
+    fn focus_moves_to_the_next_entry_when_one_is_hidden() {
+        let before = ids(&["a", "b", "c"]);
+
+        assert_eq!(
+            Some("c".into()),
+            refocus_target(&before, &ids(&["a", "c"]), "b")
+        );
+        assert_eq!(
+            Some("b".into()),
+            refocus_target(&before, &ids(&["a", "b"]), "c")
+        );
+        assert_eq!(None, refocus_target(&before, &[], "b"));
+    }
+}
@@ -0,0 +1,67 @@
+use super::data;
+use super::diff::{self, ProgressDiff};
+use super::storage::DataFormat;
+use std::fs;
+
+const USAGE: &str = "usage: remnant diff <before.json> <after.json>";
+
+/// Runs a command line subcommand and returns its output.
+///
+/// # Errors
+///
+/// Will return a message if the subcommand is unknown or an exported
+/// progress file cannot be read.
+pub fn run(args: &[String]) -> Result<String, String> {
+    match args {
+        [command, before, after] if command == "diff" => {
+            let before = read(before)?;
+            let after = read(after)?;
+            let diff = ProgressDiff::new(&before.completed_items, &after.completed_items);
+            Ok(diff::to_text(&data::entries(&[]), &diff))
+        }
+        _ => Err(USAGE.into()),
+    }
+}
+
+fn read(path: &str) -> Result<DataFormat, String> {
+    let json = fs::read_to_string(path).map_err(|error| format!("{}: {}", path, error))?;
+    DataFormat::from_json(json).map_err(|error| format!("{}: {}", path, error))
+}
+
+#[cfg(test)]
+#[allow(clippy::wildcard_imports)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    fn write(name: &str, share_param: &str) -> String {
+        let path = env::temp_dir().join(name);
+        fs::write(&path, DataFormat::from_share_param(share_param).to_json()).unwrap();
+        path.to_string_lossy().into_owned()
+    }
+
+    #[test]
+    fn unknown_subcommands_print_usage() {
+        assert_eq!(Err(USAGE.into()), run(&["merge".into()]));
+        assert_eq!(Err(USAGE.into()), run(&[]));
+    }
+
+    #[test]
+    fn diff_prints_gained_and_lost_items() {
+        let before = write("remnant-cli-before.json", "amulet-1");
+        let after = write("remnant-cli-after.json", "amulet-2");
+        let output = run(&["diff".into(), before, after]).unwrap();
+
+        assert_eq!(
+            "Gained (1)\n  Amulet\n    Butcher's Fetish\nLost (1)\n  Amulet\n    Brutal Mark\n",
+            output
+        );
+    }
+
+    #[test]
+    fn diff_reports_unreadable_files() {
+        let error = run(&["diff".into(), "missing.json".into(), "missing.json".into()]);
+
+        assert!(error.unwrap_err().starts_with("missing.json: "));
+    }
+}
@@ -0,0 +1,209 @@
+use super::data::World;
+use super::router::Route;
+use super::storage::{CompletedItem, DataFormat};
+use super::{Entry, Page};
+use strum::IntoEnumIterator;
+
+/// A second player's progress loaded beside the local checklist.
+pub struct Comparison {
+    pub completed_items: Vec<CompletedItem>,
+    pub label: String,
+}
+
+impl Comparison {
+    pub fn new(label: String, data: DataFormat) -> Self {
+        Self {
+            completed_items: data.completed_items,
+            label,
+        }
+    }
+
+    /// Reads a comparison from the `progress` parameter of a share link.
+    pub fn from_share_param(param: &str) -> Self {
+        Self::new("Shared link".into(), DataFormat::from_share_param(param))
+    }
+
+    pub fn has(&self, entry: &Entry) -> bool {
+        self.completed_items.iter().any(|item| item.matches(entry))
+    }
+
+    pub fn gap(&self, entry: &Entry) -> Option<Gap> {
+        match (entry.completed, self.has(entry)) {
+            (false, false) => Some(Gap::Shared),
+            (false, true) => Some(Gap::Local),
+            (true, false) => Some(Gap::Remote),
+            (true, true) => None,
+        }
+    }
+
+    pub fn world_gaps<'a>(&self, entries: impl Iterator<Item = &'a Entry>) -> Vec<WorldGaps> {
+        let entries: Vec<&Entry> = entries.collect();
+        World::iter()
+            .map(|world| {
+                let mut gaps = WorldGaps {
+                    world: world.clone(),
+                    ..WorldGaps::default()
+                };
+                for entry in entries.iter().filter(|e| e.worlds.contains(&world)) {
+                    match self.gap(entry) {
+                        Some(Gap::Shared) => gaps.shared += 1,
+                        Some(Gap::Local) => gaps.local += 1,
+                        Some(Gap::Remote) => gaps.remote += 1,
+                        None => {}
+                    }
+                }
+                gaps
+            })
+            .collect()
+    }
+}
+
+pub fn share_link(origin: &str, data: &DataFormat) -> String {
+    let route = Route {
+        page: Page::Compare,
+        progress: Some(data.to_share_param()),
+        ..Route::default()
+    };
+    format!("{}/{}", origin, route.to_hash())
+}
+
+/// Which player is still missing an item.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Gap {
+    Local,
+    Remote,
+    Shared,
+}
+
+#[derive(Default)]
+pub struct WorldGaps {
+    pub local: usize,
+    pub remote: usize,
+    pub shared: usize,
+    pub world: World,
+}
+
+#[cfg(test)]
+#[allow(clippy::wildcard_imports)]
+mod tests {
+    use super::*;
+    use crate::app::data::ItemType;
+
+    fn build_comparison(ids: &[u32]) -> Comparison {
+        Comparison {
+            completed_items: ids
+                .iter()
+                .map(|id| CompletedItem {
+                    completed_at: None,
+                    data_type: ItemType::Ring,
+                    id: *id,
+                })
+                .collect(),
+            label: "friend.json".into(),
+        }
+    }
+
+    #[test]
+    fn gap_reports_which_player_lacks_an_item() {
+        let comparison = build_comparison(&[2, 4]);
+
+        assert_eq!(
+            Some(Gap::Shared),
+            comparison.gap(&Entry {
+                id: 1,
+                ..Entry::default()
+            })
+        );
+        assert_eq!(
+            Some(Gap::Local),
+            comparison.gap(&Entry {
+                id: 2,
+                ..Entry::default()
+            })
+        );
+        assert_eq!(
+            Some(Gap::Remote),
+            comparison.gap(&Entry {
+                completed: true,
+                id: 3,
+                ..Entry::default()
+            })
+        );
+        assert_eq!(
+            None,
+            comparison.gap(&Entry {
+                completed: true,
+                id: 4,
+                ..Entry::default()
+            })
+        );
+    }
+
+    #[test]
+    fn gap_ignores_items_of_another_type() {
+        let comparison = Comparison {
+            completed_items: vec![CompletedItem {
+                completed_at: None,
+                data_type: ItemType::Amulet,
+                id: 1,
+            }],
+            label: "friend.json".into(),
+        };
+
+        assert_eq!(
+            Some(Gap::Shared),
+            comparison.gap(&Entry {
+                id: 1,
+                ..Entry::default()
+            })
+        );
+    }
+
+    #[test]
+    fn world_gaps_are_counted_per_world() {
+        let comparison = build_comparison(&[2]);
+        let entries = vec![
+            Entry {
+                id: 1,
+                worlds: vec![World::Rhom, World::Any],
+                ..Entry::default()
+            },
+            Entry {
+                id: 2,
+                worlds: vec![World::Rhom, World::Any],
+                ..Entry::default()
+            },
+            Entry {
+                completed: true,
+                id: 3,
+                worlds: vec![World::Corsus, World::Any],
+                ..Entry::default()
+            },
+        ];
+        let gaps = comparison.world_gaps(entries.iter());
+
+        let any = gaps.iter().find(|g| g.world == World::Any).unwrap();
+        assert_eq!((1, 1, 1), (any.shared, any.local, any.remote));
+
+        let rhom = gaps.iter().find(|g| g.world == World::Rhom).unwrap();
+        assert_eq!((1, 1, 0), (rhom.shared, rhom.local, rhom.remote));
+
+        let corsus = gaps.iter().find(|g| g.world == World::Corsus).unwrap();
+        assert_eq!((0, 0, 1), (corsus.shared, corsus.local, corsus.remote));
+    }
+
+    #[test]
+    fn comparison_is_read_from_a_share_param() {
+        let comparison = Comparison::from_share_param("ring-2,ring-5");
+        assert_eq!(2, comparison.completed_items.len());
+    }
+
+    #[test]
+    fn share_link_includes_completed_items() {
+        let data = DataFormat::from_share_param("ring-2");
+        assert_eq!(
+            "https://example.com/#/compare?progress=ring-2",
+            share_link("https://example.com", &data)
+        );
+    }
+}
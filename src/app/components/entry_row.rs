@@ -0,0 +1,91 @@
+use super::Segments;
+use yew::prelude::*;
+
+/// A single checklist row. It only re-renders when its own entry, focus or
+/// search highlighting changes, so toggling one item leaves the rest of the
+/// list alone.
+pub struct EntryRow {
+    props: Props,
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+    pub completed: bool,
+    pub focused: bool,
+    pub id: String,
+    pub item_type: String,
+    pub label: Segments,
+    /// Secondary fields the search matched, by field name.
+    pub matches: Vec<(String, Segments)>,
+    pub name: String,
+    /// Called with the entry's id and whether shift was held, which
+    /// extends the change to a range.
+    pub on_toggle: Callback<(String, bool)>,
+    pub url: String,
+    /// The name of the wiki `url` points to.
+    pub wiki: String,
+}
+
+impl Component for EntryRow {
+    type Message = ();
+    type Properties = Props;
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props == props {
+            false
+        } else {
+            self.props = props;
+            true
+        }
+    }
+
+    fn create(props: Self::Properties, _: ComponentLink<Self>) -> Self {
+        Self { props }
+    }
+
+    fn update(&mut self, _: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        let props = &self.props;
+        let mut class = "todo".to_string();
+        if props.completed {
+            class.push_str(" completed");
+        }
+        if props.focused {
+            class.push_str(" focused");
+        }
+        let id = props.id.clone();
+        let wiki_title = format!("View {} on {} wiki", props.name, props.wiki);
+
+        html! {
+            <li class=class>
+                <div class="row view">
+                    <div class="row-label">
+                        <input class="toggle" id=&props.id type="checkbox" checked=props.completed onclick=props.on_toggle.reform(move |e: MouseEvent| (id.clone(), e.shift_key())) />
+                        <label class="item-label" for=&props.id>
+                            { view_segments(&props.label) }
+                            <span class="item-type">{ &props.item_type }</span>
+                            { for props.matches.iter().map(|(field, text)| html! {
+                                <span class="match-field"><strong>{ field }</strong>{ ": " }{ view_segments(text) }</span>
+                            }) }
+                        </label>
+                    </div>
+                    <a aria-label=&wiki_title class="wiki-link" href=props.url.clone() rel="noopener noreferrer" target="_blank" title=&wiki_title>{ "wiki ↱" }</a>
+                </div>
+            </li>
+        }
+    }
+}
+
+/// Renders text with the words the search matched marked.
+fn view_segments(segments: &[(String, bool)]) -> Html {
+    html! {
+        { for segments.iter().map(|(segment, hit)| if *hit {
+            html! { <mark class="match">{ segment }</mark> }
+        } else {
+            html! { { segment } }
+        }) }
+    }
+}
@@ -0,0 +1,66 @@
+use crate::app::Filter;
+use yew::prelude::*;
+
+/// The All / Active / Completed tabs.
+pub struct FilterBar {
+    props: Props,
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+    /// Every filter with the link that selects it and how many entries in
+    /// the current view it would show.
+    pub filters: Vec<(Filter, String, usize)>,
+    pub on_select: Callback<Filter>,
+    pub selected: Filter,
+}
+
+impl Component for FilterBar {
+    type Message = ();
+    type Properties = Props;
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props == props {
+            false
+        } else {
+            self.props = props;
+            true
+        }
+    }
+
+    fn create(props: Self::Properties, _: ComponentLink<Self>) -> Self {
+        Self { props }
+    }
+
+    fn update(&mut self, _: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <ul aria-label="Show" class="filters" role="tablist">
+                { for self.props.filters.iter().map(|(filter, href, count)| self.view_filter(filter, href, *count)) }
+            </ul>
+        }
+    }
+}
+
+impl FilterBar {
+    fn view_filter(&self, filter: &Filter, href: &str, count: usize) -> Html {
+        let selected = &self.props.selected == filter;
+        let flt = filter.clone();
+
+        html! {
+            <li role="presentation">
+                <a aria-controls="checklist"
+                   aria-selected=selected.to_string()
+                   class=if selected { "selected" } else { "not-selected" }
+                   href=href
+                   role="tab"
+                   onclick=self.props.on_select.reform(move |_| flt.clone())>
+                    { format!("{} ({})", filter.as_ref(), count) }
+                </a>
+            </li>
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use super::FilterBar;
+use crate::app::Filter;
+use yew::prelude::*;
+
+/// The checklist footer: the remaining counts and the filter tabs.
+pub struct Footer {
+    props: Props,
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+    pub filters: Vec<(Filter, String, usize)>,
+    pub on_select: Callback<Filter>,
+    /// Incomplete entries matching the selected worlds, categories and
+    /// search.
+    pub remaining: usize,
+    pub selected: Filter,
+    /// Incomplete entries in the whole checklist.
+    pub total_remaining: usize,
+}
+
+impl Component for Footer {
+    type Message = ();
+    type Properties = Props;
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props == props {
+            false
+        } else {
+            self.props = props;
+            true
+        }
+    }
+
+    fn create(props: Self::Properties, _: ComponentLink<Self>) -> Self {
+        Self { props }
+    }
+
+    fn update(&mut self, _: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        let props = &self.props;
+
+        html! {
+            <footer class="footer">
+                <span class="todo-count">
+                    <strong>{ props.remaining }</strong>
+                    { " item(s) left here, " }
+                    <strong>{ props.total_remaining }</strong>
+                    { " in total" }
+                </span>
+                <FilterBar filters=props.filters.clone() on_select=props.on_select.clone() selected=props.selected.clone() />
+            </footer>
+        }
+    }
+}
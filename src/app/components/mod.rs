@@ -0,0 +1,12 @@
+mod entry_row;
+mod filter_bar;
+mod footer;
+mod world_selector;
+
+pub use entry_row::EntryRow;
+pub use filter_bar::FilterBar;
+pub use footer::Footer;
+pub use world_selector::WorldSelector;
+
+/// Text split into runs that did or did not match the search.
+pub type Segments = Vec<(String, bool)>;
@@ -0,0 +1,81 @@
+use crate::app::data::World;
+use yew::prelude::*;
+
+/// The multi-select world dropdown, showing how much is left in each world.
+pub struct WorldSelector {
+    props: Props,
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+    pub on_clear: Callback<()>,
+    pub on_toggle: Callback<World>,
+    /// Incomplete entries per specific world.
+    pub remaining: Vec<(World, usize)>,
+    pub selected: Vec<World>,
+    /// Incomplete entries across every world.
+    pub total: usize,
+}
+
+impl Component for WorldSelector {
+    type Message = ();
+    type Properties = Props;
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props == props {
+            false
+        } else {
+            self.props = props;
+            true
+        }
+    }
+
+    fn create(props: Self::Properties, _: ComponentLink<Self>) -> Self {
+        Self { props }
+    }
+
+    fn update(&mut self, _: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        let props = &self.props;
+        let summary = if props.selected.is_empty() {
+            World::Any.to_string()
+        } else {
+            let names: Vec<String> = props.selected.iter().map(World::to_string).collect();
+            names.join(", ")
+        };
+
+        html! {
+            <details class="input-world-select world-picker">
+                <summary>{ summary }</summary>
+                <ul class="list-unstyled world-options">
+                    <li>
+                        <label>
+                            <input checked=props.selected.is_empty() type="checkbox" onclick=props.on_clear.reform(|_| ()) />
+                            { format!("{} ({} left)", World::Any, props.total) }
+                        </label>
+                    </li>
+                    { for props.remaining.iter().map(|(world, count)| self.view_world(world, *count)) }
+                </ul>
+            </details>
+        }
+    }
+}
+
+impl WorldSelector {
+    fn view_world(&self, world: &World, remaining: usize) -> Html {
+        let title = format!("{} ({} left)", world, remaining);
+        let value = world.clone();
+
+        html! {
+            <li>
+                <label>
+                    <input checked=self.props.selected.contains(world) type="checkbox" onclick=self.props.on_toggle.reform(move |_| value.clone()) />
+                    { title }
+                </label>
+            </li>
+        }
+    }
+}
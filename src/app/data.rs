@@ -6,7 +6,7 @@ use std::str::FromStr;
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
 
-#[derive(Clone, Debug, EnumIter, PartialEq)]
+#[derive(Clone, Debug, Deserialize, EnumIter, PartialEq, Serialize)]
 pub enum World {
     Any,
     Corsus,
@@ -65,7 +65,7 @@ impl Display for World {
     }
 }
 
-#[derive(Clone, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, EnumIter, PartialEq, Serialize)]
 pub enum ItemType {
     Amulet,
     ArmorSet,
@@ -81,6 +81,26 @@ pub enum ItemType {
     Trait,
 }
 
+impl ItemType {
+    pub fn from_param(str: &str) -> Result<Self, String> {
+        match str {
+            "amulet" => Ok(Self::Amulet),
+            "armor-set" => Ok(Self::ArmorSet),
+            "body-armor" => Ok(Self::BodyArmor),
+            "emote" => Ok(Self::Emote),
+            "hand-gun" => Ok(Self::HandGun),
+            "head-armor" => Ok(Self::HeadArmor),
+            "leg-armor" => Ok(Self::LegArmor),
+            "long-gun" => Ok(Self::LongGun),
+            "melee-weapon" => Ok(Self::MeleeWeapon),
+            "mod" => Ok(Self::Mod),
+            "ring" => Ok(Self::Ring),
+            "trait" => Ok(Self::Trait),
+            _ => Err(format!("unknown item type: {}", str)),
+        }
+    }
+}
+
 impl Display for ItemType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let str = match self {
@@ -101,6 +121,100 @@ impl Display for ItemType {
     }
 }
 
+/// A numeric column from the weapon and armor data that entries can be
+/// sorted by.
+#[derive(Clone, Copy, Debug, Deserialize, EnumIter, PartialEq, Serialize)]
+pub enum Stat {
+    Armor,
+    BaseDamage,
+    Bleed,
+    Corrosive,
+    CritChance,
+    Fire,
+    IdealRange,
+    Magazine,
+    MaxAmmo,
+    MaxDamage,
+    Radiation,
+    Rot,
+    Rps,
+    Shock,
+    Weight,
+}
+
+impl Stat {
+    pub fn from_param(str: &str) -> Result<Self, String> {
+        match str {
+            "armor" => Ok(Self::Armor),
+            "base-damage" => Ok(Self::BaseDamage),
+            "bleed" => Ok(Self::Bleed),
+            "corrosive" => Ok(Self::Corrosive),
+            "crit-chance" => Ok(Self::CritChance),
+            "fire" => Ok(Self::Fire),
+            "ideal-range" => Ok(Self::IdealRange),
+            "magazine" => Ok(Self::Magazine),
+            "max-ammo" => Ok(Self::MaxAmmo),
+            "max-damage" => Ok(Self::MaxDamage),
+            "radiation" => Ok(Self::Radiation),
+            "rot" => Ok(Self::Rot),
+            "rps" => Ok(Self::Rps),
+            "shock" => Ok(Self::Shock),
+            "weight" => Ok(Self::Weight),
+            _ => Err(format!("unknown stat: {}", str)),
+        }
+    }
+}
+
+impl Display for Stat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            Self::Armor => "Armor",
+            Self::BaseDamage => "Base Damage",
+            Self::Bleed => "Bleed Resistance",
+            Self::Corrosive => "Corrosive Resistance",
+            Self::CritChance => "Crit Chance",
+            Self::Fire => "Fire Resistance",
+            Self::IdealRange => "Ideal Range",
+            Self::Magazine => "Magazine",
+            Self::MaxAmmo => "Max Ammo",
+            Self::MaxDamage => "Max Damage",
+            Self::Radiation => "Radiation Resistance",
+            Self::Rot => "Rot Resistance",
+            Self::Rps => "Rounds per Second",
+            Self::Shock => "Shock Resistance",
+            Self::Weight => "Weight",
+        };
+        write!(f, "{}", str)
+    }
+}
+
+/// A piece of catalog text that search can match.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Field {
+    Alias,
+    ArmorSkill,
+    Description,
+    Location,
+    Name,
+    SetBonus,
+    WeaponMod,
+}
+
+impl Display for Field {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            Self::Alias => "Alias",
+            Self::ArmorSkill => "Armor Skill",
+            Self::Description => "Description",
+            Self::Location => "Location",
+            Self::Name => "Name",
+            Self::SetBonus => "Set Bonus",
+            Self::WeaponMod => "Weapon Mod",
+        };
+        write!(f, "{}", str)
+    }
+}
+
 pub trait CsvDataSource<T>
 where
     T: EntryCompatible,
@@ -118,6 +232,14 @@ pub trait EntryCompatible {
     fn name(&self) -> &str;
     fn url(&self) -> &str;
     fn worlds_str(&self) -> &str;
+
+    fn details(&self) -> Vec<(Field, String)> {
+        vec![]
+    }
+
+    fn stats(&self) -> Vec<(Stat, f64)> {
+        vec![]
+    }
 }
 
 pub trait UrlParam {
@@ -161,16 +283,38 @@ impl UrlParam for World {
     }
 }
 
+impl UrlParam for Stat {
+    fn url_slug(self) -> &'static str {
+        match self {
+            Self::Armor => "armor",
+            Self::BaseDamage => "base-damage",
+            Self::Bleed => "bleed",
+            Self::Corrosive => "corrosive",
+            Self::CritChance => "crit-chance",
+            Self::Fire => "fire",
+            Self::IdealRange => "ideal-range",
+            Self::Magazine => "magazine",
+            Self::MaxAmmo => "max-ammo",
+            Self::MaxDamage => "max-damage",
+            Self::Radiation => "radiation",
+            Self::Rot => "rot",
+            Self::Rps => "rps",
+            Self::Shock => "shock",
+            Self::Weight => "weight",
+        }
+    }
+}
+
 #[derive(Deserialize)]
 struct Amulet {
     #[serde(rename = "Description")]
-    _description: Option<String>,
+    description: Option<String>,
 
     #[serde(rename = "ID")]
     id: u32,
 
     #[serde(rename = "Location & Crafting")]
-    _location: Option<String>,
+    location: Option<String>,
 
     #[serde(rename = "Name")]
     name: String,
@@ -191,7 +335,7 @@ struct ArmorSet {
     name: String,
 
     #[serde(rename = "Set Bonus")]
-    _set_bonus: String,
+    set_bonus: String,
 
     #[serde(rename = "Url")]
     url: String,
@@ -203,31 +347,31 @@ struct ArmorSet {
 #[derive(Deserialize)]
 struct BodyArmor {
     #[serde(rename = "Armor")]
-    _armor: Option<f32>,
+    armor: Option<f32>,
 
     #[serde(rename = "Armor Skill")]
-    _armor_skill: String,
+    armor_skill: String,
 
     #[serde(rename = "Bleed")]
-    _bleed: Option<f32>,
+    bleed: Option<f32>,
 
     #[serde(rename = "Corrosive")]
-    _corrosive: Option<f32>,
+    corrosive: Option<f32>,
 
     #[serde(rename = "Fire")]
-    _fire: Option<f32>,
+    fire: Option<f32>,
 
     #[serde(rename = "Radiation")]
-    _radiation: Option<f32>,
+    radiation: Option<f32>,
 
     #[serde(rename = "Rot")]
-    _rot: Option<f32>,
+    rot: Option<f32>,
 
     #[serde(rename = "Shock")]
-    _shock: Option<f32>,
+    shock: Option<f32>,
 
     #[serde(rename = "Weight")]
-    _weight: Option<f32>,
+    weight: Option<f32>,
 
     #[serde(rename = "ID")]
     id: u32,
@@ -245,13 +389,13 @@ struct BodyArmor {
 #[derive(Deserialize)]
 struct Emote {
     #[serde(rename = "Description")]
-    _description: String,
+    description: String,
 
     #[serde(rename = "ID")]
     id: u32,
 
     #[serde(rename = "Location")]
-    _location: String,
+    location: String,
 
     #[serde(rename = "Name")]
     name: String,
@@ -266,31 +410,31 @@ struct Emote {
 #[derive(Deserialize)]
 struct HandGun {
     #[serde(rename = "Base Damage")]
-    _base_damage: u32,
+    base_damage: u32,
 
     #[serde(rename = "Crit Chance")]
-    _crit_chance: u32,
+    crit_chance: u32,
 
     #[serde(rename = "ID")]
     id: u32,
 
     #[serde(rename = "Ideal Range")]
-    _ideal_range: u32,
+    ideal_range: u32,
 
     #[serde(rename = "Magazine")]
-    _magazine: u32,
+    magazine: u32,
 
     #[serde(rename = "Max Ammo")]
-    _max_ammo: u32,
+    max_ammo: u32,
 
     #[serde(rename = "Max Damage")]
-    _max_damage: Option<u32>,
+    max_damage: Option<u32>,
 
     #[serde(rename = "Name")]
     name: String,
 
     #[serde(rename = "RPS")]
-    _rps: f32,
+    rps: f32,
 
     #[serde(rename = "Url")]
     url: String,
@@ -302,31 +446,31 @@ struct HandGun {
 #[derive(Deserialize)]
 struct HeadArmor {
     #[serde(rename = "Armor")]
-    _armor: Option<f32>,
+    armor: Option<f32>,
 
     #[serde(rename = "Armor Skill")]
-    _armor_skill: String,
+    armor_skill: String,
 
     #[serde(rename = "Bleed")]
-    _bleed: Option<f32>,
+    bleed: Option<f32>,
 
     #[serde(rename = "Corrosive")]
-    _corrosive: Option<f32>,
+    corrosive: Option<f32>,
 
     #[serde(rename = "Fire")]
-    _fire: Option<f32>,
+    fire: Option<f32>,
 
     #[serde(rename = "Radiation")]
-    _radiation: Option<f32>,
+    radiation: Option<f32>,
 
     #[serde(rename = "Rot")]
-    _rot: Option<f32>,
+    rot: Option<f32>,
 
     #[serde(rename = "Shock")]
-    _shock: Option<f32>,
+    shock: Option<f32>,
 
     #[serde(rename = "Weight")]
-    _weight: Option<f32>,
+    weight: Option<f32>,
 
     #[serde(rename = "ID")]
     id: u32,
@@ -344,31 +488,31 @@ struct HeadArmor {
 #[derive(Deserialize)]
 struct LegArmor {
     #[serde(rename = "Armor")]
-    _armor: Option<f32>,
+    armor: Option<f32>,
 
     #[serde(rename = "Armor Skill")]
-    _armor_skill: String,
+    armor_skill: String,
 
     #[serde(rename = "Bleed")]
-    _bleed: Option<f32>,
+    bleed: Option<f32>,
 
     #[serde(rename = "Corrosive")]
-    _corrosive: Option<f32>,
+    corrosive: Option<f32>,
 
     #[serde(rename = "Fire")]
-    _fire: Option<f32>,
+    fire: Option<f32>,
 
     #[serde(rename = "Radiation")]
-    _radiation: Option<f32>,
+    radiation: Option<f32>,
 
     #[serde(rename = "Rot")]
-    _rot: Option<f32>,
+    rot: Option<f32>,
 
     #[serde(rename = "Shock")]
-    _shock: Option<f32>,
+    shock: Option<f32>,
 
     #[serde(rename = "Weight")]
-    _weight: Option<f32>,
+    weight: Option<f32>,
 
     #[serde(rename = "ID")]
     id: u32,
@@ -386,31 +530,31 @@ struct LegArmor {
 #[derive(Deserialize)]
 struct LongGun {
     #[serde(rename = "Base Damage")]
-    _base_damage: u32,
+    base_damage: u32,
 
     #[serde(rename = "Crit Chance")]
-    _crit_chance: u32,
+    crit_chance: u32,
 
     #[serde(rename = "ID")]
     id: u32,
 
     #[serde(rename = "Ideal Range")]
-    _ideal_range: u32,
+    ideal_range: u32,
 
     #[serde(rename = "Magazine")]
-    _magazine: u32,
+    magazine: u32,
 
     #[serde(rename = "Max Ammo")]
-    _max_ammo: u32,
+    max_ammo: u32,
 
     #[serde(rename = "Max Damage")]
-    _max_damage: Option<u32>,
+    max_damage: Option<u32>,
 
     #[serde(rename = "Name")]
     name: String,
 
     #[serde(rename = "RPS")]
-    _rps: f32,
+    rps: f32,
 
     #[serde(rename = "Url")]
     url: String,
@@ -422,19 +566,19 @@ struct LongGun {
 #[derive(Deserialize)]
 struct MeleeWeapon {
     #[serde(rename = "Base Damage")]
-    _base_damage: u32,
+    base_damage: u32,
 
     #[serde(rename = "ID")]
     id: u32,
 
     #[serde(rename = "Max Damage")]
-    _max_damage: Option<u32>,
+    max_damage: Option<u32>,
 
     #[serde(rename = "Name")]
     name: String,
 
     #[serde(rename = "Weapon Mod")]
-    _weapon_mod: Option<String>,
+    weapon_mod: Option<String>,
 
     #[serde(rename = "Url")]
     url: String,
@@ -445,6 +589,9 @@ struct MeleeWeapon {
 
 #[derive(Deserialize)]
 struct Mod {
+    #[serde(rename = "Description")]
+    description: String,
+
     #[serde(rename = "ID")]
     id: u32,
 
@@ -461,7 +608,7 @@ struct Mod {
 #[derive(Deserialize)]
 struct Ring {
     #[serde(rename = "Description")]
-    _description: String,
+    description: String,
 
     #[serde(rename = "ID")]
     id: u32,
@@ -470,7 +617,7 @@ struct Ring {
     name: String,
 
     #[serde(rename = "Location")]
-    _location: String,
+    location: String,
 
     #[serde(rename = "Url")]
     url: String,
@@ -482,7 +629,7 @@ struct Ring {
 #[derive(Deserialize)]
 struct Trait {
     #[serde(rename = "Description")]
-    _description: String,
+    description: String,
 
     #[serde(rename = "ID")]
     id: u32,
@@ -497,6 +644,46 @@ struct Trait {
     worlds_str: String,
 }
 
+/// Other names players use for an entry, such as abbreviations.
+pub struct Alias {
+    pub data_type: ItemType,
+    pub name: String,
+    pub names: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct AliasRow {
+    #[serde(rename = "Aliases")]
+    aliases: String,
+
+    #[serde(rename = "Name")]
+    name: String,
+
+    #[serde(rename = "Type")]
+    data_type: String,
+}
+
+const ALIASES: &[u8] = include_bytes!("../data/aliases.csv");
+
+pub fn aliases() -> Vec<Alias> {
+    let mut rdr = csv::Reader::from_reader(ALIASES);
+    rdr.deserialize()
+        .filter_map(Result::ok)
+        .filter_map(|row: AliasRow| {
+            Some(Alias {
+                data_type: ItemType::from_param(&row.data_type).ok()?,
+                name: row.name,
+                names: row
+                    .aliases
+                    .split(';')
+                    .map(str::trim)
+                    .map(String::from)
+                    .collect(),
+            })
+        })
+        .collect()
+}
+
 impl<'de, T> CsvDataSource<T> for T
 where
     T: EntryCompatible + serde::de::DeserializeOwned,
@@ -574,6 +761,19 @@ impl EntryCompatible for Amulet {
     fn worlds_str(&self) -> &str {
         &self.worlds_str
     }
+
+    fn details(&self) -> Vec<(Field, String)> {
+        collect_details(&[
+            (
+                Field::Description,
+                self.description.as_deref().unwrap_or_default(),
+            ),
+            (
+                Field::Location,
+                self.location.as_deref().unwrap_or_default(),
+            ),
+        ])
+    }
 }
 
 impl EntryCompatible for ArmorSet {
@@ -595,6 +795,10 @@ impl EntryCompatible for ArmorSet {
     fn worlds_str(&self) -> &str {
         &self.worlds_str
     }
+
+    fn details(&self) -> Vec<(Field, String)> {
+        collect_details(&[(Field::SetBonus, &self.set_bonus)])
+    }
 }
 
 impl EntryCompatible for BodyArmor {
@@ -616,6 +820,23 @@ impl EntryCompatible for BodyArmor {
     fn worlds_str(&self) -> &str {
         &self.worlds_str
     }
+
+    fn stats(&self) -> Vec<(Stat, f64)> {
+        collect_stats(&[
+            (Stat::Armor, self.armor.map(f64::from)),
+            (Stat::Bleed, self.bleed.map(f64::from)),
+            (Stat::Corrosive, self.corrosive.map(f64::from)),
+            (Stat::Fire, self.fire.map(f64::from)),
+            (Stat::Radiation, self.radiation.map(f64::from)),
+            (Stat::Rot, self.rot.map(f64::from)),
+            (Stat::Shock, self.shock.map(f64::from)),
+            (Stat::Weight, self.weight.map(f64::from)),
+        ])
+    }
+
+    fn details(&self) -> Vec<(Field, String)> {
+        collect_details(&[(Field::ArmorSkill, &self.armor_skill)])
+    }
 }
 
 impl EntryCompatible for Emote {
@@ -637,6 +858,13 @@ impl EntryCompatible for Emote {
     fn worlds_str(&self) -> &str {
         &self.worlds_str
     }
+
+    fn details(&self) -> Vec<(Field, String)> {
+        collect_details(&[
+            (Field::Description, &self.description),
+            (Field::Location, &self.location),
+        ])
+    }
 }
 
 impl EntryCompatible for HandGun {
@@ -658,6 +886,18 @@ impl EntryCompatible for HandGun {
     fn worlds_str(&self) -> &str {
         &self.worlds_str
     }
+
+    fn stats(&self) -> Vec<(Stat, f64)> {
+        collect_stats(&[
+            (Stat::BaseDamage, Some(f64::from(self.base_damage))),
+            (Stat::CritChance, Some(f64::from(self.crit_chance))),
+            (Stat::IdealRange, Some(f64::from(self.ideal_range))),
+            (Stat::Magazine, Some(f64::from(self.magazine))),
+            (Stat::MaxAmmo, Some(f64::from(self.max_ammo))),
+            (Stat::MaxDamage, self.max_damage.map(f64::from)),
+            (Stat::Rps, Some(f64::from(self.rps))),
+        ])
+    }
 }
 
 impl EntryCompatible for HeadArmor {
@@ -679,6 +919,23 @@ impl EntryCompatible for HeadArmor {
     fn worlds_str(&self) -> &str {
         &self.worlds_str
     }
+
+    fn stats(&self) -> Vec<(Stat, f64)> {
+        collect_stats(&[
+            (Stat::Armor, self.armor.map(f64::from)),
+            (Stat::Bleed, self.bleed.map(f64::from)),
+            (Stat::Corrosive, self.corrosive.map(f64::from)),
+            (Stat::Fire, self.fire.map(f64::from)),
+            (Stat::Radiation, self.radiation.map(f64::from)),
+            (Stat::Rot, self.rot.map(f64::from)),
+            (Stat::Shock, self.shock.map(f64::from)),
+            (Stat::Weight, self.weight.map(f64::from)),
+        ])
+    }
+
+    fn details(&self) -> Vec<(Field, String)> {
+        collect_details(&[(Field::ArmorSkill, &self.armor_skill)])
+    }
 }
 
 impl EntryCompatible for LegArmor {
@@ -700,6 +957,23 @@ impl EntryCompatible for LegArmor {
     fn worlds_str(&self) -> &str {
         &self.worlds_str
     }
+
+    fn stats(&self) -> Vec<(Stat, f64)> {
+        collect_stats(&[
+            (Stat::Armor, self.armor.map(f64::from)),
+            (Stat::Bleed, self.bleed.map(f64::from)),
+            (Stat::Corrosive, self.corrosive.map(f64::from)),
+            (Stat::Fire, self.fire.map(f64::from)),
+            (Stat::Radiation, self.radiation.map(f64::from)),
+            (Stat::Rot, self.rot.map(f64::from)),
+            (Stat::Shock, self.shock.map(f64::from)),
+            (Stat::Weight, self.weight.map(f64::from)),
+        ])
+    }
+
+    fn details(&self) -> Vec<(Field, String)> {
+        collect_details(&[(Field::ArmorSkill, &self.armor_skill)])
+    }
 }
 
 impl EntryCompatible for LongGun {
@@ -721,6 +995,18 @@ impl EntryCompatible for LongGun {
     fn worlds_str(&self) -> &str {
         &self.worlds_str
     }
+
+    fn stats(&self) -> Vec<(Stat, f64)> {
+        collect_stats(&[
+            (Stat::BaseDamage, Some(f64::from(self.base_damage))),
+            (Stat::CritChance, Some(f64::from(self.crit_chance))),
+            (Stat::IdealRange, Some(f64::from(self.ideal_range))),
+            (Stat::Magazine, Some(f64::from(self.magazine))),
+            (Stat::MaxAmmo, Some(f64::from(self.max_ammo))),
+            (Stat::MaxDamage, self.max_damage.map(f64::from)),
+            (Stat::Rps, Some(f64::from(self.rps))),
+        ])
+    }
 }
 
 impl EntryCompatible for MeleeWeapon {
@@ -742,6 +1028,20 @@ impl EntryCompatible for MeleeWeapon {
     fn worlds_str(&self) -> &str {
         &self.worlds_str
     }
+
+    fn stats(&self) -> Vec<(Stat, f64)> {
+        collect_stats(&[
+            (Stat::BaseDamage, Some(f64::from(self.base_damage))),
+            (Stat::MaxDamage, self.max_damage.map(f64::from)),
+        ])
+    }
+
+    fn details(&self) -> Vec<(Field, String)> {
+        collect_details(&[(
+            Field::WeaponMod,
+            self.weapon_mod.as_deref().unwrap_or_default(),
+        )])
+    }
 }
 
 impl EntryCompatible for Mod {
@@ -763,6 +1063,10 @@ impl EntryCompatible for Mod {
     fn worlds_str(&self) -> &str {
         &self.worlds_str
     }
+
+    fn details(&self) -> Vec<(Field, String)> {
+        collect_details(&[(Field::Description, &self.description)])
+    }
 }
 
 impl EntryCompatible for Ring {
@@ -784,6 +1088,13 @@ impl EntryCompatible for Ring {
     fn worlds_str(&self) -> &str {
         &self.worlds_str
     }
+
+    fn details(&self) -> Vec<(Field, String)> {
+        collect_details(&[
+            (Field::Description, &self.description),
+            (Field::Location, &self.location),
+        ])
+    }
 }
 
 impl EntryCompatible for Trait {
@@ -805,200 +1116,75 @@ impl EntryCompatible for Trait {
     fn worlds_str(&self) -> &str {
         &self.worlds_str
     }
+
+    fn details(&self) -> Vec<(Field, String)> {
+        collect_details(&[(Field::Description, &self.description)])
+    }
 }
 
 impl<T: CsvDataSource<T> + EntryCompatible> From<T> for Entry {
     fn from(item: T) -> Self {
         Self {
             completed: false,
+            completed_at: None,
             data_type: T::DATA_TYPE,
+            details: item.details(),
             id: item.id(),
             name: String::from(item.name()),
+            stats: item.stats(),
             url: String::from(item.url()),
             worlds: item.worlds(),
         }
     }
 }
 
-pub fn amulet_entries(defaults: &[CompletedItem]) -> Vec<Entry> {
-    let mut entries = Amulet::entries();
-
-    #[allow(clippy::blocks_in_if_conditions)]
-    for entry in &mut entries {
-        if defaults
-            .iter()
-            .any(|default| default.id == entry.id && matches!(default.data_type, ItemType::Amulet))
-        {
-            entry.completed = true;
-        }
-    }
-    entries
-}
-
-pub fn armor_set_entries(defaults: &[CompletedItem]) -> Vec<Entry> {
-    let mut entries = ArmorSet::entries();
-
-    #[allow(clippy::blocks_in_if_conditions)]
-    for entry in &mut entries {
-        if defaults.iter().any(|default| {
-            default.id == entry.id && matches!(default.data_type, ItemType::ArmorSet)
-        }) {
-            entry.completed = true;
-        }
-    }
-    entries
-}
-
-pub fn body_armor_entries(defaults: &[CompletedItem]) -> Vec<Entry> {
-    let mut entries = BodyArmor::entries();
-
-    #[allow(clippy::blocks_in_if_conditions)]
-    for entry in &mut entries {
-        if defaults.iter().any(|default| {
-            default.id == entry.id && matches!(default.data_type, ItemType::BodyArmor)
-        }) {
-            entry.completed = true;
-        }
-    }
-    entries
-}
-
-pub fn emote_entries(defaults: &[CompletedItem]) -> Vec<Entry> {
-    let mut entries = Emote::entries();
-
-    #[allow(clippy::blocks_in_if_conditions)]
-    for entry in &mut entries {
-        if defaults
-            .iter()
-            .any(|default| default.id == entry.id && matches!(default.data_type, ItemType::Emote))
-        {
-            entry.completed = true;
-        }
-    }
-    entries
-}
-
-pub fn hand_gun_entries(defaults: &[CompletedItem]) -> Vec<Entry> {
-    let mut entries = HandGun::entries();
-
-    #[allow(clippy::blocks_in_if_conditions)]
-    for entry in &mut entries {
-        if defaults
-            .iter()
-            .any(|default| default.id == entry.id && matches!(default.data_type, ItemType::HandGun))
-        {
-            entry.completed = true;
-        }
-    }
-    entries
-}
-
-pub fn head_armor_entries(defaults: &[CompletedItem]) -> Vec<Entry> {
-    let mut entries = HeadArmor::entries();
-
-    #[allow(clippy::blocks_in_if_conditions)]
-    for entry in &mut entries {
-        if defaults.iter().any(|default| {
-            default.id == entry.id && matches!(default.data_type, ItemType::HeadArmor)
-        }) {
-            entry.completed = true;
-        }
-    }
-    entries
-}
-
-pub fn leg_armor_entries(defaults: &[CompletedItem]) -> Vec<Entry> {
-    let mut entries = LegArmor::entries();
-
-    #[allow(clippy::blocks_in_if_conditions)]
-    for entry in &mut entries {
-        if defaults.iter().any(|default| {
-            default.id == entry.id && matches!(default.data_type, ItemType::LegArmor)
-        }) {
-            entry.completed = true;
-        }
-    }
-    entries
-}
-
-pub fn long_gun_entries(defaults: &[CompletedItem]) -> Vec<Entry> {
-    let mut entries = LongGun::entries();
-
-    #[allow(clippy::blocks_in_if_conditions)]
-    for entry in &mut entries {
-        if defaults
-            .iter()
-            .any(|default| default.id == entry.id && matches!(default.data_type, ItemType::LongGun))
-        {
-            entry.completed = true;
-        }
-    }
-    entries
-}
-
-pub fn melee_weapon_entries(defaults: &[CompletedItem]) -> Vec<Entry> {
-    let mut entries = MeleeWeapon::entries();
-
-    #[allow(clippy::blocks_in_if_conditions)]
-    for entry in &mut entries {
-        if defaults.iter().any(|default| {
-            default.id == entry.id && matches!(default.data_type, ItemType::MeleeWeapon)
-        }) {
-            entry.completed = true;
-        }
-    }
-    entries
-}
-
-pub fn mod_entries(defaults: &[CompletedItem]) -> Vec<Entry> {
-    let mut entries = Mod::entries();
-
-    #[allow(clippy::blocks_in_if_conditions)]
-    for entry in &mut entries {
-        if defaults
-            .iter()
-            .any(|default| default.id == entry.id && matches!(default.data_type, ItemType::Mod))
-        {
-            entry.completed = true;
-        }
-    }
-    entries
-}
-
-pub fn remnant_trait_entries(defaults: &[CompletedItem]) -> Vec<Entry> {
+/// Every catalog entry, marking the ones found in `defaults` as completed.
+pub fn entries(defaults: &[CompletedItem]) -> Vec<Entry> {
     let mut entries = Trait::entries();
+    entries.append(&mut Amulet::entries());
+    entries.append(&mut ArmorSet::entries());
+    entries.append(&mut HeadArmor::entries());
+    entries.append(&mut BodyArmor::entries());
+    entries.append(&mut LegArmor::entries());
+    entries.append(&mut Emote::entries());
+    entries.append(&mut Ring::entries());
+    entries.append(&mut HandGun::entries());
+    entries.append(&mut LongGun::entries());
+    entries.append(&mut MeleeWeapon::entries());
+    entries.append(&mut Mod::entries());
 
-    #[allow(clippy::blocks_in_if_conditions)]
     for entry in &mut entries {
-        if defaults
-            .iter()
-            .any(|default| default.id == entry.id && matches!(default.data_type, ItemType::Trait))
-        {
+        if let Some(default) = defaults.iter().find(|default| default.matches(entry)) {
             entry.completed = true;
+            entry.completed_at = default.completed_at;
         }
     }
     entries
 }
 
-pub fn ring_entries(defaults: &[CompletedItem]) -> Vec<Entry> {
-    let mut entries = Ring::entries();
+/// Keeps only the text columns a sheet actually filled in.
+fn collect_details(details: &[(Field, &str)]) -> Vec<(Field, String)> {
+    details
+        .iter()
+        .map(|(field, text)| (*field, text.trim()))
+        .filter(|(_, text)| !text.is_empty())
+        .map(|(field, text)| (field, text.to_string()))
+        .collect()
+}
 
-    #[allow(clippy::blocks_in_if_conditions)]
-    for entry in &mut entries {
-        if defaults
-            .iter()
-            .any(|default| default.id == entry.id && matches!(default.data_type, ItemType::Ring))
-        {
-            entry.completed = true;
-        }
-    }
-    entries
+/// Keeps only the stats a sheet actually has a value for.
+fn collect_stats(stats: &[(Stat, Option<f64>)]) -> Vec<(Stat, f64)> {
+    stats
+        .iter()
+        .filter_map(|(stat, value)| value.map(|value| (*stat, value)))
+        .collect()
 }
 
 #[cfg(test)]
 #[allow(clippy::wildcard_imports)]
 mod tests {
     use super::*;
+    use chrono::Utc;
 
     const NUMBER_OF_AMULETS: usize = 37;
     const NUMBER_OF_ARMOR_SETS: usize = 19;
@@ -1259,9 +1445,9 @@ mod tests {
     #[test]
     fn worlds_str_is_converted_to_a_vec_of_worlds() {
         let amulet = Amulet {
-            _description: None,
+            description: None,
             id: 1,
-            _location: None,
+            location: None,
             name: String::from("example"),
             url: String::from("www.example.com"),
             worlds_str: String::from("Ward 17,Earth"),
@@ -1276,9 +1462,9 @@ mod tests {
     #[test]
     fn worlds_str_ignores_invalid_input() {
         let amulet = Amulet {
-            _description: None,
+            description: None,
             id: 1,
-            _location: None,
+            location: None,
             name: String::from("example"),
             url: String::from("www.example.com"),
             worlds_str: String::from("Nope,Earth,Invalid"),
@@ -1290,9 +1476,9 @@ mod tests {
     #[test]
     fn any_worlds_str_is_converted_to_a_vec_of_all_worlds() {
         let amulet = Amulet {
-            _description: None,
+            description: None,
             id: 1,
-            _location: None,
+            location: None,
             name: String::from("example"),
             url: String::from("www.example.com"),
             worlds_str: String::from("Any"),
@@ -1315,4 +1501,103 @@ mod tests {
             amulet.worlds()
         );
     }
+
+    #[test]
+    fn item_type_url_slugs_round_trip() {
+        for item_type in ItemType::iter() {
+            assert_eq!(
+                Ok(item_type.clone()),
+                ItemType::from_param(item_type.url_slug())
+            );
+        }
+    }
+
+    #[test]
+    fn unknown_item_type_param_is_an_error() {
+        assert!(ItemType::from_param("shield").is_err());
+    }
+
+    #[test]
+    fn all_aliases_name_catalog_entries() {
+        let entries = entries(&[]);
+        let aliases = aliases();
+
+        assert_eq!(15, aliases.len());
+        for alias in aliases {
+            assert!(
+                entries
+                    .iter()
+                    .any(|entry| entry.data_type == alias.data_type && entry.name == alias.name),
+                "unknown alias target: {}",
+                alias.name
+            );
+        }
+    }
+
+    #[test]
+    fn entries_keep_their_descriptive_text() {
+        let entries = entries(&[]);
+        let detail = |name: &str, field: Field| {
+            entries
+                .iter()
+                .find(|entry| entry.name == name)
+                .and_then(|entry| entry.details.iter().find(|(f, _)| *f == field))
+                .map(|(_, text)| text.clone())
+        };
+
+        assert_eq!(
+            Some("Treasure Hunter".to_string()),
+            detail("Adventurer Tunic", Field::ArmorSkill)
+        );
+        assert_eq!(
+            Some("Increases Max Health.".to_string()),
+            detail("Vigor", Field::Description)
+        );
+        assert_eq!(None, detail("Vigor", Field::Location));
+    }
+
+    #[test]
+    fn stat_url_slugs_round_trip() {
+        for stat in Stat::iter() {
+            assert_eq!(Ok(stat), Stat::from_param(stat.url_slug()));
+        }
+    }
+
+    #[test]
+    fn weapons_and_armor_keep_their_stats() {
+        let entries = entries(&[]);
+        let has_stat = |data_type: ItemType, stat: Stat| {
+            entries
+                .iter()
+                .filter(|entry| entry.data_type == data_type)
+                .any(|entry| entry.stats.iter().any(|(s, _)| *s == stat))
+        };
+
+        assert!(has_stat(ItemType::LongGun, Stat::BaseDamage));
+        assert!(has_stat(ItemType::MeleeWeapon, Stat::BaseDamage));
+        assert!(has_stat(ItemType::BodyArmor, Stat::Armor));
+        assert!(has_stat(ItemType::LegArmor, Stat::Weight));
+        assert!(entries
+            .iter()
+            .filter(|entry| entry.data_type == ItemType::Ring)
+            .all(|entry| entry.stats.is_empty()));
+    }
+
+    #[test]
+    fn entries_restore_completion_times() {
+        let completed_at = Utc::now();
+        let defaults = vec![CompletedItem {
+            completed_at: Some(completed_at),
+            data_type: ItemType::Ring,
+            id: 1,
+        }];
+        let entries = entries(&defaults);
+        let ring = entries
+            .iter()
+            .find(|entry| entry.data_type == ItemType::Ring && entry.id == 1)
+            .unwrap();
+
+        assert!(ring.completed);
+        assert_eq!(Some(completed_at), ring.completed_at);
+    }
 }
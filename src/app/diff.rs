@@ -0,0 +1,182 @@
+use super::data::{ItemType, UrlParam};
+use super::storage::{CompletedItem, DataFormat};
+use super::Entry;
+use std::fmt::Write;
+use strum::IntoEnumIterator;
+
+/// What changed between two progress snapshots.
+#[derive(Debug, Default, PartialEq)]
+pub struct ProgressDiff {
+    pub gained: Vec<CompletedItem>,
+    pub lost: Vec<CompletedItem>,
+}
+
+impl ProgressDiff {
+    pub fn new(before: &[CompletedItem], after: &[CompletedItem]) -> Self {
+        Self {
+            gained: after
+                .iter()
+                .filter(|item| !before.iter().any(|other| other.is_same_item(item)))
+                .cloned()
+                .collect(),
+            lost: before
+                .iter()
+                .filter(|item| !after.iter().any(|other| other.is_same_item(item)))
+                .cloned()
+                .collect(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.gained.is_empty() && self.lost.is_empty()
+    }
+}
+
+/// Which side of a diff a loaded snapshot belongs to.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Side {
+    Before,
+    After,
+}
+
+/// A named progress payload loaded for diffing.
+pub struct Snapshot {
+    pub data: DataFormat,
+    pub label: String,
+}
+
+/// Groups items by `ItemType`, in catalog order, skipping empty groups.
+pub fn group_by_type(items: &[CompletedItem]) -> Vec<(ItemType, Vec<&CompletedItem>)> {
+    ItemType::iter()
+        .map(|data_type| {
+            let items: Vec<&CompletedItem> = items
+                .iter()
+                .filter(|item| item.data_type == data_type)
+                .collect();
+            (data_type, items)
+        })
+        .filter(|(_, items)| !items.is_empty())
+        .collect()
+}
+
+/// The catalog name for an item, falling back to its id when the item is
+/// no longer in the catalog.
+pub fn name(entries: &[Entry], item: &CompletedItem) -> String {
+    entries
+        .iter()
+        .find(|entry| item.matches(entry))
+        .map_or_else(
+            || format!("Unknown {} #{}", item.data_type.clone().url_slug(), item.id),
+            |entry| entry.name.clone(),
+        )
+}
+
+/// Renders a diff as indented plain text for the command line.
+pub fn to_text(entries: &[Entry], diff: &ProgressDiff) -> String {
+    // Writing to a `String` cannot fail, so the results are ignored.
+    let mut text = String::new();
+    for (title, items) in &[("Gained", &diff.gained), ("Lost", &diff.lost)] {
+        let _ = writeln!(text, "{} ({})", title, items.len());
+        for (data_type, items) in group_by_type(items) {
+            let _ = writeln!(text, "  {}", data_type);
+            for item in items {
+                let _ = writeln!(text, "    {}", name(entries, item));
+            }
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+#[allow(clippy::wildcard_imports)]
+mod tests {
+    use super::*;
+
+    fn item(data_type: ItemType, id: u32) -> CompletedItem {
+        CompletedItem {
+            completed_at: None,
+            data_type,
+            id,
+        }
+    }
+
+    #[test]
+    fn diff_lists_gained_and_lost_items() {
+        let before = vec![item(ItemType::Ring, 1), item(ItemType::Amulet, 2)];
+        let after = vec![item(ItemType::Ring, 1), item(ItemType::Ring, 3)];
+        let diff = ProgressDiff::new(&before, &after);
+
+        assert_eq!(vec![item(ItemType::Ring, 3)], diff.gained);
+        assert_eq!(vec![item(ItemType::Amulet, 2)], diff.lost);
+    }
+
+    #[test]
+    fn diff_ignores_completion_times() {
+        let before = vec![item(ItemType::Ring, 1)];
+        let after = vec![CompletedItem {
+            completed_at: Some(chrono::Utc::now()),
+            ..item(ItemType::Ring, 1)
+        }];
+
+        assert!(ProgressDiff::new(&before, &after).is_empty());
+    }
+
+    #[test]
+    fn diff_of_identical_snapshots_is_empty() {
+        let items = vec![item(ItemType::Ring, 1)];
+
+        assert!(ProgressDiff::new(&items, &items).is_empty());
+    }
+
+    #[test]
+    fn group_by_type_uses_catalog_order_and_skips_empty_groups() {
+        let items = vec![
+            item(ItemType::Ring, 1),
+            item(ItemType::Amulet, 2),
+            item(ItemType::Ring, 3),
+        ];
+        let groups = group_by_type(&items);
+
+        assert_eq!(2, groups.len());
+        assert_eq!(ItemType::Amulet, groups[0].0);
+        assert_eq!(ItemType::Ring, groups[1].0);
+        assert_eq!(2, groups[1].1.len());
+    }
+
+    #[test]
+    fn name_falls_back_for_unknown_items() {
+        let entries = vec![Entry {
+            data_type: ItemType::Ring,
+            id: 1,
+            name: "Blood Font".into(),
+            ..Entry::default()
+        }];
+
+        assert_eq!("Blood Font", name(&entries, &item(ItemType::Ring, 1)));
+        assert_eq!("Unknown ring #9", name(&entries, &item(ItemType::Ring, 9)));
+    }
+
+    #[test]
+    fn to_text_lists_items_by_type() {
+        let entries = vec![
+            Entry {
+                data_type: ItemType::Ring,
+                id: 1,
+                name: "Blood Font".into(),
+                ..Entry::default()
+            },
+            Entry {
+                data_type: ItemType::Amulet,
+                id: 2,
+                name: "Brutal Mark".into(),
+                ..Entry::default()
+            },
+        ];
+        let diff = ProgressDiff::new(&[item(ItemType::Amulet, 2)], &[item(ItemType::Ring, 1)]);
+
+        assert_eq!(
+            "Gained (1)\n  Ring\n    Blood Font\nLost (1)\n  Amulet\n    Brutal Mark\n",
+            to_text(&entries, &diff)
+        );
+    }
+}
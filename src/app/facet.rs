@@ -0,0 +1,223 @@
+use super::data::{ItemType, World};
+use super::Entry;
+use serde::{de::DeserializeOwned, Serialize as SerializeTrait};
+use serde_derive::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+
+/// A multi-select filter combined with OR semantics. Selecting nothing
+/// shows everything.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(bound = "T: SerializeTrait + DeserializeOwned", transparent)]
+pub struct Facet<T> {
+    selected: Vec<T>,
+}
+
+pub type ItemTypeFacet = Facet<ItemType>;
+pub type WorldFacet = Facet<World>;
+
+impl<T> Default for Facet<T> {
+    fn default() -> Self {
+        Self { selected: vec![] }
+    }
+}
+
+impl<T: PartialEq> Facet<T> {
+    pub fn new(selected: Vec<T>) -> Self {
+        Self { selected }
+    }
+
+    pub fn clear(&mut self) {
+        self.selected.clear();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.selected.is_empty()
+    }
+
+    pub fn is_selected(&self, value: &T) -> bool {
+        self.selected.contains(value)
+    }
+
+    pub fn selected(&self) -> &[T] {
+        &self.selected
+    }
+
+    pub fn toggle(&mut self, value: T) {
+        if let Some(index) = self.selected.iter().position(|v| v == &value) {
+            self.selected.remove(index);
+        } else {
+            self.selected.push(value);
+        }
+    }
+}
+
+impl Facet<ItemType> {
+    pub fn fit(&self, entry: &Entry) -> bool {
+        self.is_empty() || self.is_selected(&entry.data_type)
+    }
+}
+
+impl Facet<World> {
+    pub fn fit(&self, entry: &Entry) -> bool {
+        self.is_empty() || entry.worlds.iter().any(|world| self.is_selected(world))
+    }
+}
+
+/// The number of entries of each item type, in catalog order.
+pub fn counts<'a>(entries: impl Iterator<Item = &'a Entry>) -> Vec<(ItemType, usize)> {
+    let entries: Vec<&Entry> = entries.collect();
+    ItemType::iter()
+        .map(|item_type| {
+            let count = entries
+                .iter()
+                .filter(|entry| entry.data_type == item_type)
+                .count();
+            (item_type, count)
+        })
+        .collect()
+}
+
+/// The number of incomplete entries that drop in each specific world.
+pub fn remaining_by_world<'a>(entries: impl Iterator<Item = &'a Entry>) -> Vec<(World, usize)> {
+    let entries: Vec<&Entry> = entries.filter(|entry| !entry.completed).collect();
+    World::iter()
+        .filter(|world| world != &World::Any)
+        .map(|world| {
+            let count = entries
+                .iter()
+                .filter(|entry| entry.worlds.contains(&world))
+                .count();
+            (world, count)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+#[allow(clippy::wildcard_imports)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_facet_fits_every_entry() {
+        let facet = ItemTypeFacet::default();
+
+        assert!(facet.fit(&Entry {
+            data_type: ItemType::Ring,
+            ..Entry::default()
+        }));
+        assert!(facet.fit(&Entry {
+            data_type: ItemType::Emote,
+            ..Entry::default()
+        }));
+    }
+
+    #[test]
+    fn selected_types_are_combined_with_or() {
+        let mut facet = ItemTypeFacet::default();
+        facet.toggle(ItemType::Ring);
+        facet.toggle(ItemType::Amulet);
+
+        assert!(facet.fit(&Entry {
+            data_type: ItemType::Ring,
+            ..Entry::default()
+        }));
+        assert!(facet.fit(&Entry {
+            data_type: ItemType::Amulet,
+            ..Entry::default()
+        }));
+        assert!(!facet.fit(&Entry {
+            data_type: ItemType::Emote,
+            ..Entry::default()
+        }));
+    }
+
+    #[test]
+    fn toggle_deselects_a_selected_type() {
+        let mut facet = ItemTypeFacet::default();
+        facet.toggle(ItemType::Ring);
+        facet.toggle(ItemType::Ring);
+
+        assert!(facet.is_empty());
+        assert!(!facet.is_selected(&ItemType::Ring));
+    }
+
+    #[test]
+    fn counts_include_every_type() {
+        let entries = vec![
+            Entry {
+                data_type: ItemType::Ring,
+                ..Entry::default()
+            },
+            Entry {
+                data_type: ItemType::Ring,
+                ..Entry::default()
+            },
+            Entry {
+                data_type: ItemType::Amulet,
+                ..Entry::default()
+            },
+        ];
+        let counts = counts(entries.iter());
+
+        assert_eq!(ItemType::iter().count(), counts.len());
+        assert_eq!((ItemType::Amulet, 1), counts[0]);
+        assert!(counts.contains(&(ItemType::Ring, 2)));
+        assert!(counts.contains(&(ItemType::Emote, 0)));
+    }
+
+    #[test]
+    fn world_facet_matches_any_selected_world() {
+        let facet = WorldFacet::new(vec![World::Rhom, World::Corsus]);
+
+        assert!(facet.fit(&Entry {
+            worlds: vec![World::Rhom, World::Any],
+            ..Entry::default()
+        }));
+        assert!(facet.fit(&Entry {
+            worlds: vec![World::Corsus, World::Any],
+            ..Entry::default()
+        }));
+        assert!(!facet.fit(&Entry {
+            worlds: vec![World::Earth, World::Any],
+            ..Entry::default()
+        }));
+        assert!(WorldFacet::default().fit(&Entry {
+            worlds: vec![World::Any],
+            ..Entry::default()
+        }));
+    }
+
+    #[test]
+    fn remaining_by_world_skips_completed_entries_and_any_world() {
+        let entries = vec![
+            Entry {
+                worlds: vec![World::Rhom, World::Any],
+                ..Entry::default()
+            },
+            Entry {
+                completed: true,
+                worlds: vec![World::Rhom, World::Any],
+                ..Entry::default()
+            },
+            Entry {
+                worlds: World::iter().collect(),
+                ..Entry::default()
+            },
+        ];
+        let remaining = remaining_by_world(entries.iter());
+
+        assert_eq!(World::iter().count() - 1, remaining.len());
+        assert!(remaining.contains(&(World::Rhom, 2)));
+        assert!(remaining.contains(&(World::Corsus, 1)));
+        assert!(!remaining.iter().any(|(world, _)| world == &World::Any));
+    }
+
+    #[test]
+    fn facet_serializes_as_a_list() {
+        let mut facet = ItemTypeFacet::default();
+        facet.toggle(ItemType::LongGun);
+        let json: yew::format::Text = yew::format::Json(&facet).into();
+
+        assert_eq!("[\"LongGun\"]", json.unwrap());
+    }
+}
@@ -0,0 +1,278 @@
+use super::data::UrlParam;
+use super::sort::world_position;
+use super::storage::{CompletedItem, DataFormat};
+use super::Entry;
+use std::cmp::Reverse;
+use strum_macros::{AsRefStr, EnumIter};
+
+/// One player's progress in the group matrix.
+#[derive(Clone)]
+pub struct Member {
+    pub completed_items: Vec<CompletedItem>,
+    pub name: String,
+}
+
+impl Member {
+    pub fn new(name: String, data: DataFormat) -> Self {
+        Self {
+            completed_items: data.completed_items,
+            name,
+        }
+    }
+
+    pub fn has(&self, entry: &Entry) -> bool {
+        self.completed_items.iter().any(|item| item.matches(entry))
+    }
+
+    pub fn total<'a>(&self, entries: impl Iterator<Item = &'a Entry>) -> usize {
+        entries.filter(|entry| self.has(entry)).count()
+    }
+}
+
+#[derive(AsRefStr, Clone, Debug, EnumIter, PartialEq)]
+pub enum GroupSort {
+    #[strum(serialize = "Item type")]
+    ItemType,
+    #[strum(serialize = "World")]
+    World,
+    #[strum(serialize = "Members lacking")]
+    Lacking,
+}
+
+impl GroupSort {
+    pub fn from_param(str: &str) -> Result<Self, String> {
+        match str {
+            "item-type" => Ok(Self::ItemType),
+            "world" => Ok(Self::World),
+            "lacking" => Ok(Self::Lacking),
+            _ => Err(format!("unknown group sort: {}", str)),
+        }
+    }
+}
+
+impl UrlParam for GroupSort {
+    fn url_slug(self) -> &'static str {
+        match self {
+            Self::ItemType => "item-type",
+            Self::World => "world",
+            Self::Lacking => "lacking",
+        }
+    }
+}
+
+/// How many members are still missing an entry.
+pub fn lacking(members: &[Member], entry: &Entry) -> usize {
+    members.iter().filter(|member| !member.has(entry)).count()
+}
+
+/// Orders entries for the group matrix, keeping names alphabetical within
+/// each bucket.
+pub fn sort<'a>(
+    members: &[Member],
+    mut entries: Vec<&'a Entry>,
+    sort: &GroupSort,
+) -> Vec<&'a Entry> {
+    match sort {
+        GroupSort::ItemType => {
+            entries.sort_by_key(|entry| (entry.data_type.to_string(), entry.name.clone()));
+        }
+        GroupSort::World => {
+            entries.sort_by_key(|entry| (world_position(entry), entry.name.clone()));
+        }
+        GroupSort::Lacking => {
+            entries.sort_by_key(|entry| (Reverse(lacking(members, entry)), entry.name.clone()));
+        }
+    }
+    entries
+}
+
+#[cfg(test)]
+#[allow(clippy::wildcard_imports)]
+mod tests {
+    use super::*;
+    use crate::app::data::{ItemType, World};
+    use strum::IntoEnumIterator;
+
+    fn build_member(items: &[(ItemType, u32)]) -> Member {
+        Member {
+            completed_items: items
+                .iter()
+                .map(|(data_type, id)| CompletedItem {
+                    completed_at: None,
+                    data_type: data_type.clone(),
+                    id: *id,
+                })
+                .collect(),
+            name: "member.json".into(),
+        }
+    }
+
+    fn names(entries: &[&Entry]) -> Vec<String> {
+        entries.iter().map(|entry| entry.name.clone()).collect()
+    }
+
+    #[test]
+    fn lacking_counts_members_without_the_item() {
+        let members = vec![
+            build_member(&[(ItemType::Ring, 1)]),
+            build_member(&[(ItemType::Ring, 1), (ItemType::Ring, 2)]),
+            build_member(&[]),
+        ];
+
+        assert_eq!(
+            1,
+            lacking(
+                &members,
+                &Entry {
+                    data_type: ItemType::Ring,
+                    id: 1,
+                    name: "A".into(),
+                    ..Entry::default()
+                }
+            )
+        );
+        assert_eq!(
+            2,
+            lacking(
+                &members,
+                &Entry {
+                    data_type: ItemType::Ring,
+                    id: 2,
+                    name: "B".into(),
+                    ..Entry::default()
+                }
+            )
+        );
+        assert_eq!(
+            3,
+            lacking(
+                &members,
+                &Entry {
+                    data_type: ItemType::Amulet,
+                    id: 1,
+                    name: "C".into(),
+                    ..Entry::default()
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn member_totals_only_count_known_entries() {
+        let member = build_member(&[(ItemType::Ring, 1), (ItemType::Ring, 99)]);
+        let entries = vec![
+            Entry {
+                data_type: ItemType::Ring,
+                id: 1,
+                name: "A".into(),
+                ..Entry::default()
+            },
+            Entry {
+                data_type: ItemType::Ring,
+                id: 2,
+                name: "B".into(),
+                ..Entry::default()
+            },
+        ];
+
+        assert_eq!(1, member.total(entries.iter()));
+    }
+
+    #[test]
+    fn sort_by_lacking_puts_the_rarest_items_first() {
+        let members = vec![
+            build_member(&[(ItemType::Ring, 1)]),
+            build_member(&[(ItemType::Ring, 1), (ItemType::Ring, 2)]),
+        ];
+        let entries = vec![
+            Entry {
+                data_type: ItemType::Ring,
+                id: 1,
+                name: "Common".into(),
+                ..Entry::default()
+            },
+            Entry {
+                data_type: ItemType::Ring,
+                id: 2,
+                name: "Uncommon".into(),
+                ..Entry::default()
+            },
+            Entry {
+                data_type: ItemType::Ring,
+                id: 3,
+                name: "Rare".into(),
+                ..Entry::default()
+            },
+        ];
+        let sorted = sort(&members, entries.iter().collect(), &GroupSort::Lacking);
+
+        assert_eq!(vec!["Rare", "Uncommon", "Common"], names(&sorted));
+    }
+
+    #[test]
+    fn sort_by_item_type_groups_types_together() {
+        let entries = vec![
+            Entry {
+                data_type: ItemType::Ring,
+                id: 1,
+                name: "A Ring".into(),
+                ..Entry::default()
+            },
+            Entry {
+                data_type: ItemType::Amulet,
+                id: 1,
+                name: "B Amulet".into(),
+                ..Entry::default()
+            },
+            Entry {
+                data_type: ItemType::Ring,
+                id: 2,
+                name: "C Ring".into(),
+                ..Entry::default()
+            },
+        ];
+        let sorted = sort(&[], entries.iter().collect(), &GroupSort::ItemType);
+
+        assert_eq!(vec!["B Amulet", "A Ring", "C Ring"], names(&sorted));
+    }
+
+    #[test]
+    fn sort_by_world_puts_items_from_any_world_last() {
+        let entries = vec![
+            Entry {
+                data_type: ItemType::Ring,
+                id: 1,
+                name: "Anywhere".into(),
+                worlds: World::iter().collect(),
+                ..Entry::default()
+            },
+            Entry {
+                data_type: ItemType::Ring,
+                id: 2,
+                name: "Yaesha".into(),
+                worlds: vec![World::Yaesha, World::Any],
+                ..Entry::default()
+            },
+            Entry {
+                data_type: ItemType::Ring,
+                id: 3,
+                name: "Corsus".into(),
+                worlds: vec![World::Corsus, World::Any],
+                ..Entry::default()
+            },
+        ];
+        let sorted = sort(&[], entries.iter().collect(), &GroupSort::World);
+
+        assert_eq!(vec!["Corsus", "Yaesha", "Anywhere"], names(&sorted));
+    }
+
+    #[test]
+    fn group_sort_url_slugs_round_trip() {
+        for sort in GroupSort::iter() {
+            assert_eq!(
+                Ok(sort.clone()),
+                GroupSort::from_param(sort.clone().url_slug())
+            );
+        }
+    }
+}
@@ -0,0 +1,227 @@
+use super::Entry;
+use chrono::{DateTime, NaiveDate, Utc};
+use std::cmp::Reverse;
+use std::convert::TryFrom;
+
+const CHART_HEIGHT: usize = 400;
+const CHART_WIDTH: usize = 1000;
+
+/// An inclusive range of UTC days. Either end may be left open.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DateRange {
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+}
+
+impl DateRange {
+    pub fn contains(&self, at: &DateTime<Utc>) -> bool {
+        let day = at.naive_utc().date();
+        self.from.map_or(true, |from| day >= from) && self.to.map_or(true, |to| day <= to)
+    }
+
+    /// Reads the `YYYY-MM-DD` value of a date input, treating anything
+    /// else as an open end.
+    pub fn parse_day(value: &str) -> Option<NaiveDate> {
+        NaiveDate::parse_from_str(value, "%Y-%m-%d").ok()
+    }
+
+    fn is_before(&self, at: &DateTime<Utc>) -> bool {
+        self.from.map_or(false, |from| at.naive_utc().date() < from)
+    }
+}
+
+/// Entries completed within `range`, newest first.
+pub fn unlocked<'a>(entries: impl Iterator<Item = &'a Entry>, range: &DateRange) -> Vec<&'a Entry> {
+    let mut unlocked: Vec<&Entry> = entries
+        .filter(|entry| entry.completed_at.map_or(false, |at| range.contains(&at)))
+        .collect();
+    unlocked.sort_by_key(|entry| Reverse(entry.completed_at));
+    unlocked
+}
+
+/// Completed entries saved before completion times were recorded.
+pub fn undated<'a>(entries: impl Iterator<Item = &'a Entry>) -> usize {
+    entries
+        .filter(|entry| entry.completed && entry.completed_at.is_none())
+        .count()
+}
+
+/// The running total of completed entries at the end of each day something
+/// was unlocked within `range`. The total starts from everything completed
+/// before the range, including undated completions.
+pub fn cumulative<'a>(
+    entries: impl Iterator<Item = &'a Entry> + Clone,
+    range: &DateRange,
+) -> Vec<(NaiveDate, usize)> {
+    let mut total = entries
+        .clone()
+        .filter(|entry| entry.completed)
+        .filter(|entry| entry.completed_at.map_or(true, |at| range.is_before(&at)))
+        .count();
+
+    let days: Vec<NaiveDate> = unlocked(entries, range)
+        .iter()
+        .filter_map(|entry| entry.completed_at)
+        .map(|at| at.naive_utc().date())
+        .collect();
+
+    let mut points: Vec<(NaiveDate, usize)> = vec![];
+    for day in days.into_iter().rev() {
+        total += 1;
+        match points.last_mut() {
+            Some((last, count)) if *last == day => *count = total,
+            _ => points.push((day, total)),
+        }
+    }
+    points
+}
+
+/// Scales cumulative totals into `polyline` points for a chart drawn in a
+/// `0 0 1000 400` view box, with zero at the bottom. Days are spaced by the
+/// time between them, from the first day on the left to the last on the
+/// right.
+pub fn chart_points(points: &[(NaiveDate, usize)]) -> String {
+    let max = points.iter().map(|(_, count)| *count).max().unwrap_or(0);
+    let y = |count: usize| CHART_HEIGHT - (count * CHART_HEIGHT).checked_div(max).unwrap_or(0);
+
+    match points {
+        [] => "".into(),
+        [(_, count)] => format!("0,{y} {w},{y}", y = y(*count), w = CHART_WIDTH),
+        [(first, _), .., (last, _)] => {
+            let span = days_between(*first, *last);
+            points
+                .iter()
+                .map(|(day, count)| {
+                    let x = (days_between(*first, *day) * CHART_WIDTH)
+                        .checked_div(span)
+                        .unwrap_or(0);
+                    format!("{},{}", x, y(*count))
+                })
+                .collect::<Vec<String>>()
+                .join(" ")
+        }
+    }
+}
+
+fn days_between(from: NaiveDate, to: NaiveDate) -> usize {
+    usize::try_from((to - from).num_days()).unwrap_or(0)
+}
+
+#[cfg(test)]
+#[allow(clippy::wildcard_imports)]
+mod tests {
+    use super::*;
+
+    fn day(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2020, 9, day).unwrap()
+    }
+
+    fn time(value: &str) -> DateTime<Utc> {
+        value.parse().unwrap()
+    }
+
+    fn entries() -> Vec<Entry> {
+        vec![
+            Entry {
+                completed: true,
+                name: "Undated".into(),
+                ..Entry::default()
+            },
+            Entry {
+                completed: true,
+                completed_at: Some(time("2020-09-01T10:00:00Z")),
+                name: "First".into(),
+                ..Entry::default()
+            },
+            Entry {
+                completed: true,
+                completed_at: Some(time("2020-09-03T09:00:00Z")),
+                name: "Second".into(),
+                ..Entry::default()
+            },
+            Entry {
+                completed: true,
+                completed_at: Some(time("2020-09-03T21:00:00Z")),
+                name: "Third".into(),
+                ..Entry::default()
+            },
+            Entry {
+                name: "Incomplete".into(),
+                ..Entry::default()
+            },
+        ]
+    }
+
+    fn names(entries: &[&Entry]) -> Vec<String> {
+        entries.iter().map(|entry| entry.name.clone()).collect()
+    }
+
+    #[test]
+    fn unlocked_is_newest_first_and_respects_the_range() {
+        let entries = entries();
+
+        assert_eq!(
+            vec!["Third", "Second", "First"],
+            names(&unlocked(entries.iter(), &DateRange::default()))
+        );
+        assert_eq!(
+            vec!["First"],
+            names(&unlocked(
+                entries.iter(),
+                &DateRange {
+                    from: None,
+                    to: Some(day(2)),
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn undated_counts_completions_without_a_time() {
+        assert_eq!(1, undated(entries().iter()));
+    }
+
+    #[test]
+    fn cumulative_totals_per_day_start_from_earlier_progress() {
+        let entries = entries();
+
+        assert_eq!(
+            vec![(day(1), 2), (day(3), 4)],
+            cumulative(entries.iter(), &DateRange::default())
+        );
+        assert_eq!(
+            vec![(day(3), 4)],
+            cumulative(
+                entries.iter(),
+                &DateRange {
+                    from: Some(day(2)),
+                    to: None,
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn chart_points_scale_to_the_view_box() {
+        assert_eq!("", chart_points(&[]));
+        assert_eq!("0,0 1000,0", chart_points(&[(day(1), 3)]));
+        assert_eq!(
+            "0,200 500,100 1000,0",
+            chart_points(&[(day(1), 2), (day(2), 3), (day(3), 4)])
+        );
+    }
+
+    #[test]
+    fn chart_points_are_spaced_by_date() {
+        assert_eq!(
+            "0,200 250,100 1000,0",
+            chart_points(&[(day(1), 2), (day(2), 3), (day(5), 4)])
+        );
+    }
+
+    #[test]
+    fn parse_day_reads_date_inputs() {
+        assert_eq!(Some(day(14)), DateRange::parse_day("2020-09-14"));
+        assert_eq!(None, DateRange::parse_day(""));
+    }
+}
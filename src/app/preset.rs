@@ -0,0 +1,100 @@
+use super::facet::{ItemTypeFacet, WorldFacet};
+use super::Filter;
+use serde_derive::{Deserialize, Serialize};
+
+/// A named checklist view: the completion filter, selected worlds and item
+/// types, and search text, e.g. "incomplete Yaesha rings".
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Preset {
+    pub filter: Filter,
+    pub item_types: ItemTypeFacet,
+    pub name: String,
+    pub search: String,
+    pub worlds: WorldFacet,
+}
+
+impl Preset {
+    /// A one line summary of what the preset selects, used as its tooltip.
+    pub fn describe(&self) -> String {
+        let mut parts = vec![self.filter.as_ref().to_string()];
+        if !self.worlds.is_empty() {
+            let worlds: Vec<String> = self
+                .worlds
+                .selected()
+                .iter()
+                .map(ToString::to_string)
+                .collect();
+            parts.push(worlds.join(", "));
+        }
+        if !self.item_types.is_empty() {
+            let types: Vec<String> = self
+                .item_types
+                .selected()
+                .iter()
+                .map(ToString::to_string)
+                .collect();
+            parts.push(types.join(", "));
+        }
+        if !self.search.is_empty() {
+            parts.push(format!("\"{}\"", self.search));
+        }
+        parts.join(" · ")
+    }
+}
+
+/// Adds a preset, replacing any existing one with the same name so saving
+/// again updates it in place.
+pub fn save(presets: &mut Vec<Preset>, preset: Preset) {
+    let name = preset.name.to_lowercase();
+    match presets.iter_mut().find(|p| p.name.to_lowercase() == name) {
+        Some(existing) => *existing = preset,
+        None => presets.push(preset),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::wildcard_imports)]
+mod tests {
+    use super::*;
+    use crate::app::data::{ItemType, World};
+    use yew::format::Json;
+
+    fn build_preset(name: &str, search: &str) -> Preset {
+        Preset {
+            filter: Filter::Active,
+            item_types: ItemTypeFacet::new(vec![ItemType::Ring]),
+            name: name.into(),
+            search: search.into(),
+            worlds: WorldFacet::new(vec![World::Yaesha]),
+        }
+    }
+
+    #[test]
+    fn save_replaces_a_preset_with_the_same_name() {
+        let mut presets = vec![build_preset("Yaesha rings", ""), build_preset("Other", "")];
+        save(&mut presets, build_preset("yaesha rings", "crit"));
+        save(&mut presets, build_preset("New", ""));
+
+        let names: Vec<&str> = presets.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(vec!["yaesha rings", "Other", "New"], names);
+        assert_eq!("crit", presets[0].search);
+    }
+
+    #[test]
+    fn describe_lists_the_selection() {
+        assert_eq!("Active · Yaesha · Ring", build_preset("a", "").describe());
+        assert_eq!(
+            "Active · Yaesha · Ring · \"crit\"",
+            build_preset("a", "crit").describe()
+        );
+    }
+
+    #[test]
+    fn presets_round_trip_through_json() {
+        let presets = vec![build_preset("Yaesha rings", "crit")];
+        let json: yew::format::Text = Json(&presets).into();
+        let Json(restored): Json<Result<Vec<Preset>, _>> = Json::from(json);
+
+        assert_eq!(presets, restored.unwrap());
+    }
+}
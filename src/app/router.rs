@@ -0,0 +1,228 @@
+use super::data::{UrlParam, World};
+use super::{Filter, Page};
+use std::convert::TryFrom;
+
+/// The view state mirrored into the URL fragment, e.g.
+/// `#/active?world=rhom,corsus&q=ring`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Route {
+    pub filter: Option<Filter>,
+    pub page: Page,
+    pub progress: Option<String>,
+    pub search: String,
+    pub worlds: Vec<World>,
+}
+
+impl Default for Route {
+    fn default() -> Self {
+        Self {
+            filter: None,
+            page: Page::Checklist,
+            progress: None,
+            search: "".into(),
+            worlds: vec![],
+        }
+    }
+}
+
+impl Route {
+    /// Parses a location hash, ignoring anything it does not recognize.
+    pub fn parse(hash: &str) -> Self {
+        let hash = hash.trim_start_matches('#');
+        let (path, query) = hash.split_once('?').unwrap_or((hash, ""));
+
+        let mut route = Self::default();
+        match path {
+            "/" => route.filter = Some(Filter::All),
+            "/active" => route.filter = Some(Filter::Active),
+            "/completed" => route.filter = Some(Filter::Completed),
+            "/compare" => route.page = Page::Compare,
+            "/diff" => route.page = Page::Diff,
+            "/group" => route.page = Page::Group,
+            "/history" => route.page = Page::History,
+            "/settings" => route.page = Page::Settings,
+            "/stats" => route.page = Page::Stats,
+            _ => {}
+        }
+
+        for pair in query.split('&') {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            let value = decode(value);
+            match key {
+                "progress" => route.progress = Some(value),
+                "q" => route.search = value,
+                "world" => {
+                    route.worlds = value
+                        .split(',')
+                        .filter_map(|world| World::from_param(world).ok())
+                        .filter(|world| world != &World::Any)
+                        .collect();
+                }
+                _ => {}
+            }
+        }
+        route
+    }
+
+    pub fn to_hash(&self) -> String {
+        let path = match (&self.page, &self.filter) {
+            (Page::Checklist, Some(Filter::Active)) => "/active",
+            (Page::Checklist, Some(Filter::Completed)) => "/completed",
+            (Page::Checklist, _) => "/",
+            (Page::Compare, _) => "/compare",
+            (Page::Diff, _) => "/diff",
+            (Page::Group, _) => "/group",
+            (Page::History, _) => "/history",
+            (Page::Settings, _) => "/settings",
+            (Page::Stats, _) => "/stats",
+        };
+
+        let mut params = vec![];
+        if !self.worlds.is_empty() {
+            let worlds: Vec<&str> = self
+                .worlds
+                .iter()
+                .map(|world| world.clone().url_slug())
+                .collect();
+            params.push(format!("world={}", worlds.join(",")));
+        }
+        if !self.search.is_empty() {
+            params.push(format!("q={}", encode(&self.search)));
+        }
+        if let Some(progress) = &self.progress {
+            params.push(format!("progress={}", encode(progress)));
+        }
+
+        if params.is_empty() {
+            format!("#{}", path)
+        } else {
+            format!("#{}?{}", path, params.join("&"))
+        }
+    }
+}
+
+/// Percent-encodes everything except unreserved characters and the commas
+/// used to separate item ids.
+fn encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b',' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+fn decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let hex = |index: usize| {
+        bytes
+            .get(index)
+            .and_then(|byte| char::from(*byte).to_digit(16))
+            .and_then(|digit| u8::try_from(digit).ok())
+    };
+
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        match (bytes[index], hex(index + 1), hex(index + 2)) {
+            (b'%', Some(high), Some(low)) => {
+                decoded.push(high * 16 + low);
+                index += 2;
+            }
+            (b'+', _, _) => decoded.push(b' '),
+            (byte, _, _) => decoded.push(byte),
+        }
+        index += 1;
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+#[cfg(test)]
+#[allow(clippy::wildcard_imports)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_hash_keeps_the_default_filter() {
+        assert_eq!(Route::default(), Route::parse(""));
+    }
+
+    #[test]
+    fn filter_world_and_search_are_parsed() {
+        let route = Route::parse("#/active?world=rhom&q=ring");
+
+        assert_eq!(Some(Filter::Active), route.filter);
+        assert_eq!(Page::Checklist, route.page);
+        assert_eq!(vec![World::Rhom], route.worlds);
+        assert_eq!("ring", route.search);
+    }
+
+    #[test]
+    fn several_worlds_are_parsed() {
+        let route = Route::parse("#/?world=rhom,corsus,any,mars");
+
+        assert_eq!(vec![World::Rhom, World::Corsus], route.worlds);
+        assert_eq!("#/?world=rhom,corsus", route.to_hash());
+    }
+
+    #[test]
+    fn root_path_shows_all_entries() {
+        assert_eq!(Some(Filter::All), Route::parse("#/").filter);
+    }
+
+    #[test]
+    fn pages_are_parsed() {
+        assert_eq!(Page::Compare, Route::parse("#/compare").page);
+        assert_eq!(Page::Diff, Route::parse("#/diff").page);
+        assert_eq!(Page::Group, Route::parse("#/group").page);
+        assert_eq!(Page::History, Route::parse("#/history").page);
+        assert_eq!(Page::Stats, Route::parse("#/stats").page);
+        assert_eq!(Page::Settings, Route::parse("#/settings").page);
+    }
+
+    #[test]
+    fn unknown_values_are_ignored() {
+        let route = Route::parse("#/nope?world=mars&sort=name");
+
+        assert_eq!(Route::default(), route);
+    }
+
+    #[test]
+    fn routes_round_trip() {
+        let route = Route {
+            filter: Some(Filter::Completed),
+            search: "butcher's fetish & more".into(),
+            worlds: vec![World::Yaesha],
+            ..Route::default()
+        };
+        let hash = route.to_hash();
+
+        assert_eq!(
+            "#/completed?world=yaesha&q=butcher%27s%20fetish%20%26%20more",
+            hash
+        );
+        assert_eq!(route, Route::parse(&hash));
+    }
+
+    #[test]
+    fn progress_is_kept_readable() {
+        let route = Route {
+            page: Page::Compare,
+            progress: Some("amulet-1,ring-4".into()),
+            ..Route::default()
+        };
+
+        assert_eq!("#/compare?progress=amulet-1,ring-4", route.to_hash());
+    }
+
+    #[test]
+    fn decode_handles_plus_and_malformed_escapes() {
+        assert_eq!("a b", decode("a+b"));
+        assert_eq!("100%", decode("100%"));
+        assert_eq!("%zz", decode("%zz"));
+        assert_eq!("é", decode("%C3%A9"));
+    }
+}
@@ -0,0 +1,105 @@
+use super::index::tokenize;
+use super::{Query, SearchIndex};
+
+/// Splits text into runs that did or did not match a query word, so views
+/// can mark exactly what a search found. Words are compared the same way
+/// the index compares them, so words containing a query word, prefixes and
+/// the typos `index` would accept are highlighted too.
+pub fn highlight(text: &str, query: &Query, index: &SearchIndex) -> Vec<(String, bool)> {
+    let mut segments: Vec<(String, bool)> = vec![];
+    let mut push = |part: String, hit: bool| match segments.last_mut() {
+        Some((last, last_hit)) if *last_hit == hit => last.push_str(&part),
+        _ => segments.push((part, hit)),
+    };
+
+    let mut word = String::new();
+    for c in text.chars() {
+        if is_word_char(c) {
+            word.push(c);
+        } else {
+            if !word.is_empty() {
+                let hit = matches(&word, query, index);
+                push(std::mem::take(&mut word), hit);
+            }
+            push(c.to_string(), false);
+        }
+    }
+    if !word.is_empty() {
+        let hit = matches(&word, query, index);
+        push(word, hit);
+    }
+    segments
+}
+
+/// Apostrophes are dropped rather than splitting words when tokenizing, so
+/// "Hunter's" stays one word here as well.
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '\'' | '’' | '`')
+}
+
+fn matches(word: &str, query: &Query, index: &SearchIndex) -> bool {
+    tokenize(word)
+        .iter()
+        .any(|token| query.words().iter().any(|wanted| index.hits(wanted, token)))
+}
+
+#[cfg(test)]
+#[allow(clippy::wildcard_imports)]
+mod tests {
+    use super::*;
+    use crate::app::Entry;
+
+    fn marked(text: &str, query: &str) -> Vec<(String, bool)> {
+        highlight(text, &Query::new(query), &SearchIndex::default())
+    }
+
+    #[test]
+    fn matched_words_are_marked() {
+        assert_eq!(
+            vec![
+                ("Ring of the ".to_string(), false),
+                ("Unseen".to_string(), true)
+            ],
+            marked("Ring of the Unseen", "unseen")
+        );
+    }
+
+    #[test]
+    fn prefixes_typos_and_apostrophes_are_marked() {
+        assert_eq!(
+            vec![
+                ("Hunter's".to_string(), true),
+                (" ".to_string(), false),
+                ("Band".to_string(), true)
+            ],
+            marked("Hunter's Band", "hunters bamd")
+        );
+        assert_eq!(
+            vec![("Vengeance Idol".to_string(), false)],
+            marked("Vengeance Idol", "ring")
+        );
+    }
+
+    #[test]
+    fn typos_the_search_rejects_are_not_marked() {
+        let entries = vec![Entry {
+            name: "Hunter's Band".into(),
+            ..Entry::default()
+        }];
+        let index = SearchIndex::new(&entries);
+
+        // "band" is found as typed, so the search does not accept "Brand".
+        assert_eq!(
+            vec![("Brand".to_string(), false)],
+            highlight("Brand", &Query::new("band"), &index)
+        );
+    }
+
+    #[test]
+    fn filters_alone_mark_nothing() {
+        assert_eq!(
+            vec![("Braided Thorns".to_string(), false)],
+            marked("Braided Thorns", "type:ring")
+        );
+    }
+}
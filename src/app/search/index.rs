@@ -0,0 +1,496 @@
+use super::Query;
+use crate::app::data::{self, Field};
+use crate::app::Entry;
+use std::cell::RefCell;
+use std::cmp::min;
+use std::collections::HashMap;
+
+const EXACT: usize = 4;
+const PREFIX: usize = 3;
+const SUBSTRING: usize = 2;
+const TYPO: usize = 1;
+/// How many words `SearchIndex` remembers typo decisions for before it
+/// starts over, so a long session does not grow the cache without end.
+const LITERAL_LIMIT: usize = 64;
+
+/// How well an entry matched a query, and which fields other than its name
+/// the match came from. Item types are left to `type:` filters.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Match {
+    pub fields: Vec<Field>,
+    pub score: usize,
+}
+
+/// One searchable field of an entry, normalized once.
+struct Document {
+    field: Field,
+    text: String,
+    tokens: Vec<String>,
+}
+
+impl Document {
+    fn new(field: Field, text: &str) -> Self {
+        let tokens = tokenize(text);
+        Self {
+            field,
+            text: tokens.join(" "),
+            tokens,
+        }
+    }
+
+    /// How well a word matches this field. Names and aliases match anywhere
+    /// in their text, like the original substring search, while descriptive
+    /// text needs a whole word or its start. Typos only count when `typos`
+    /// is set.
+    fn score(&self, wanted: &str, typos: bool) -> Option<usize> {
+        let best = self
+            .tokens
+            .iter()
+            .filter_map(|token| token_score(wanted, token))
+            .max();
+        match self.field {
+            Field::Name | Field::Alias if self.text.contains(wanted) => {
+                Some(best.map_or(SUBSTRING, |score| score.max(SUBSTRING)))
+            }
+            Field::Name | Field::Alias if typos => best,
+            _ => best.filter(|score| *score > TYPO),
+        }
+    }
+}
+
+/// Normalized text for every searchable field of every entry, built once at
+/// load. Alias text is kept as well since entries do not carry it.
+#[derive(Default)]
+pub struct SearchIndex {
+    aliases: HashMap<String, Vec<String>>,
+    documents: HashMap<String, Vec<Document>>,
+    /// Whether recently searched words match anything without typos. Words
+    /// that do not fall back to typo matching on names.
+    literal: RefCell<HashMap<String, bool>>,
+}
+
+impl SearchIndex {
+    pub fn new(entries: &[Entry]) -> Self {
+        let aliases = data::aliases();
+        let mut names = HashMap::new();
+        let documents = entries
+            .iter()
+            .map(|entry| {
+                let mut fields = vec![Document::new(Field::Name, &entry.name)];
+                for alias in aliases
+                    .iter()
+                    .filter(|alias| alias.data_type == entry.data_type && alias.name == entry.name)
+                {
+                    for name in &alias.names {
+                        fields.push(Document::new(Field::Alias, name));
+                    }
+                    names
+                        .entry(entry.id())
+                        .or_insert_with(Vec::new)
+                        .extend(alias.names.iter().cloned());
+                }
+                for (field, text) in &entry.details {
+                    fields.push(Document::new(*field, text));
+                }
+                (entry.id(), fields)
+            })
+            .collect();
+        Self {
+            aliases: names,
+            documents,
+            literal: RefCell::default(),
+        }
+    }
+
+    /// Other names players search an entry by.
+    pub fn aliases(&self, entry: &Entry) -> &[String] {
+        self.aliases.get(&entry.id()).map_or(&[], Vec::as_slice)
+    }
+
+    /// Matches every query word against the entry, or returns `None` when
+    /// any word is missing or a filter does not fit. A word is found in a
+    /// name or alias as a substring, or as a whole word or prefix elsewhere;
+    /// typos are only tried for words found nowhere. Exact tokens rank above
+    /// prefixes, then substrings, then typos, and names and aliases count
+    /// double. An empty query matches everything equally.
+    pub fn search(&self, entry: &Entry, query: &Query) -> Option<Match> {
+        let fields = self.documents.get(&entry.id())?;
+        if !query.fits(entry) {
+            return None;
+        }
+
+        let mut found = Match::default();
+        for wanted in query.words() {
+            let typos = !self.is_literal(wanted);
+            let (field, score) = fields
+                .iter()
+                .filter_map(|document| {
+                    let score = document.score(wanted, typos)?;
+                    Some((document.field, score * weight(document.field)))
+                })
+                .max_by_key(|(_, score)| *score)?;
+
+            found.score += score;
+            if field != Field::Name && !found.fields.contains(&field) {
+                found.fields.push(field);
+            }
+        }
+        Some(found)
+    }
+
+    pub fn score(&self, entry: &Entry, query: &Query) -> Option<usize> {
+        self.search(entry, query).map(|found| found.score)
+    }
+
+    /// Whether one word of some text is a hit for a query word, judged the
+    /// way `search` judges names, so only what the search accepted is
+    /// highlighted.
+    pub fn hits(&self, wanted: &str, token: &str) -> bool {
+        token.contains(wanted)
+            || matches!(
+                token_score(wanted, token),
+                Some(score) if score > TYPO || !self.is_literal(wanted)
+            )
+    }
+
+    /// Whether a word matches any entry without typos, worked out once per
+    /// word.
+    fn is_literal(&self, wanted: &str) -> bool {
+        if let Some(literal) = self.literal.borrow().get(wanted) {
+            return *literal;
+        }
+
+        let literal = self
+            .documents
+            .values()
+            .flatten()
+            .any(|document| document.score(wanted, false).is_some());
+        let mut cache = self.literal.borrow_mut();
+        if cache.len() >= LITERAL_LIMIT {
+            cache.clear();
+        }
+        cache.insert(wanted.into(), literal);
+        literal
+    }
+}
+
+const fn weight(field: Field) -> usize {
+    match field {
+        Field::Name | Field::Alias => 2,
+        _ => 1,
+    }
+}
+
+/// Lowercases text, folds common diacritics, drops apostrophes and treats
+/// any other punctuation as a word break.
+pub fn normalize(text: &str) -> String {
+    let mut normalized = String::with_capacity(text.len());
+    for c in text.chars().flat_map(char::to_lowercase) {
+        match c {
+            '\'' | '’' | '`' => {}
+            'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' => normalized.push('a'),
+            'æ' => normalized.push_str("ae"),
+            'ç' => normalized.push('c'),
+            'è' | 'é' | 'ê' | 'ë' => normalized.push('e'),
+            'ì' | 'í' | 'î' | 'ï' => normalized.push('i'),
+            'ñ' => normalized.push('n'),
+            'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' => normalized.push('o'),
+            'œ' => normalized.push_str("oe"),
+            'ß' => normalized.push_str("ss"),
+            'ù' | 'ú' | 'û' | 'ü' => normalized.push('u'),
+            'ý' | 'ÿ' => normalized.push('y'),
+            c if c.is_alphanumeric() => normalized.push(c),
+            _ => normalized.push(' '),
+        }
+    }
+    normalized
+        .split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+pub fn tokenize(text: &str) -> Vec<String> {
+    normalize(text)
+        .split(' ')
+        .filter(|token| !token.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// The number of single character insertions, deletions, substitutions or
+/// adjacent swaps needed to turn one word into another.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut rows = vec![(0..=b.len()).collect::<Vec<usize>>()];
+
+    for i in 1..=a.len() {
+        let mut row = vec![i; b.len() + 1];
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            row[j] = min(
+                min(rows[i - 1][j] + 1, row[j - 1] + 1),
+                rows[i - 1][j - 1] + cost,
+            );
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                row[j] = min(row[j], rows[i - 2][j - 2] + 1);
+            }
+        }
+        rows.push(row);
+    }
+    rows[a.len()][b.len()]
+}
+
+/// Short words must match exactly, longer ones may contain a typo or two.
+fn tolerance(token: &str) -> usize {
+    match token.chars().count() {
+        0..=3 => 0,
+        4..=7 => 1,
+        _ => 2,
+    }
+}
+
+fn token_score(wanted: &str, token: &str) -> Option<usize> {
+    if wanted == token {
+        Some(EXACT)
+    } else if token.starts_with(wanted) {
+        Some(PREFIX)
+    } else if tolerance(wanted) > 0 && edit_distance(wanted, token) <= tolerance(wanted) {
+        Some(TYPO)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::wildcard_imports)]
+mod tests {
+    use super::*;
+    use crate::app::data::ItemType;
+
+    fn score(entry: &Entry, text: &str) -> Option<usize> {
+        SearchIndex::new(std::slice::from_ref(entry)).score(entry, &Query::new(text))
+    }
+
+    #[test]
+    fn normalize_folds_case_punctuation_and_diacritics() {
+        assert_eq!("butchers fetish", normalize("Butcher's Fetish"));
+        assert_eq!("sawed off", normalize("Sawed-Off"));
+        assert_eq!("cafe creme", normalize("  Café   Crème! "));
+    }
+
+    #[test]
+    fn edit_distance_counts_swaps_as_one_edit() {
+        assert_eq!(0, edit_distance("rifle", "rifle"));
+        assert_eq!(1, edit_distance("rifel", "rifle"));
+        assert_eq!(1, edit_distance("rife", "rifle"));
+        assert_eq!(3, edit_distance("", "abc"));
+    }
+
+    #[test]
+    fn search_ignores_punctuation() {
+        let entry = Entry {
+            data_type: ItemType::Amulet,
+            id: 1,
+            name: "Butcher's Fetish".into(),
+            ..Entry::default()
+        };
+
+        assert!(score(&entry, "butchers fetish").is_some());
+        assert!(score(&entry, "BUTCHER'S").is_some());
+    }
+
+    #[test]
+    fn search_matches_word_prefixes_in_any_order() {
+        let entry = Entry {
+            data_type: ItemType::LongGun,
+            id: 1,
+            name: "Hunting Rifle".into(),
+            ..Entry::default()
+        };
+
+        assert!(score(&entry, "hunt rifle").is_some());
+        assert!(score(&entry, "rifle hunt").is_some());
+        assert!(score(&entry, "hunt pistol").is_none());
+    }
+
+    #[test]
+    fn search_tolerates_typos_in_longer_words() {
+        let entry = Entry {
+            data_type: ItemType::LongGun,
+            id: 7,
+            name: "Sniper Rifle".into(),
+            ..Entry::default()
+        };
+
+        assert!(score(&entry, "snipr").is_some());
+        assert!(score(&entry, "sniper rilfe").is_some());
+        assert!(score(&entry, "rif").is_some());
+        assert!(score(&entry, "sni rufle").is_some());
+        assert!(score(&entry, "snx").is_none());
+    }
+
+    #[test]
+    fn exact_matches_rank_above_prefixes_and_typos() {
+        let entry = Entry {
+            data_type: ItemType::LongGun,
+            id: 3,
+            name: "Shotgun".into(),
+            ..Entry::default()
+        };
+
+        assert!(score(&entry, "shotgun") > score(&entry, "shot"));
+        assert!(score(&entry, "shot") > score(&entry, "shotgum"));
+    }
+
+    #[test]
+    fn search_matches_aliases() {
+        let entry = Entry {
+            data_type: ItemType::LongGun,
+            id: 14,
+            name: "Chicago Typewriter".into(),
+            ..Entry::default()
+        };
+
+        assert!(score(&entry, "tommy gun").is_some());
+        assert!(SearchIndex::new(std::slice::from_ref(&entry))
+            .aliases(&entry)
+            .contains(&"tommy gun".to_string()));
+    }
+
+    #[test]
+    fn search_matches_descriptive_text_and_reports_the_field() {
+        let entry = Entry {
+            data_type: ItemType::ArmorSet,
+            details: vec![(Field::ArmorSkill, "Treasure Hunter".into())],
+            id: 1,
+            name: "Adventurer Set".into(),
+            ..Entry::default()
+        };
+        let index = SearchIndex::new(std::slice::from_ref(&entry));
+
+        assert_eq!(
+            Some(vec![Field::ArmorSkill]),
+            index
+                .search(&entry, &Query::new("the treasure hunter set"))
+                .map(|found| found.fields)
+        );
+        assert_eq!(
+            Some(vec![]),
+            index
+                .search(&entry, &Query::new("adventurer"))
+                .map(|found| found.fields)
+        );
+    }
+
+    #[test]
+    fn plain_words_do_not_match_item_types() {
+        let entry = Entry {
+            data_type: ItemType::HandGun,
+            id: 1,
+            name: "Repeater Pistol".into(),
+            ..Entry::default()
+        };
+
+        assert!(score(&entry, "gun").is_none());
+        assert!(score(&entry, "band").is_none());
+    }
+
+    #[test]
+    fn typos_are_only_tried_when_nothing_matches_literally() {
+        let entries = vec![
+            Entry {
+                data_type: ItemType::Ring,
+                id: 1,
+                name: "Hunter's Band".into(),
+                ..Entry::default()
+            },
+            Entry {
+                data_type: ItemType::Ring,
+                id: 2,
+                name: "Hand of Doom".into(),
+                ..Entry::default()
+            },
+        ];
+        let index = SearchIndex::new(&entries);
+
+        assert!(index.score(&entries[1], &Query::new("band")).is_none());
+        assert!(index.score(&entries[0], &Query::new("bamd")).is_some());
+    }
+
+    #[test]
+    fn plain_words_find_what_substring_search_found() {
+        let entries = data::entries(&[]);
+        let index = SearchIndex::new(&entries);
+        let hits = |text: &str| -> Vec<&str> {
+            let query = Query::new(text);
+            entries
+                .iter()
+                .filter(|entry| index.search(entry, &query).is_some())
+                .map(|entry| entry.name.as_str())
+                .collect()
+        };
+
+        assert_eq!(
+            vec![
+                "Driftstone",
+                "Polished Whetstone",
+                "Amber Moonstone",
+                "Celerity Stone",
+                "Gravity Stone",
+                "Pillar of Stone",
+                "Razorstone",
+                "Sagestone",
+                "Spirit Stone",
+                "Stone Of Balance"
+            ],
+            hits("stone")
+        );
+        assert_eq!(
+            vec![
+                "Guardian's Blessing",
+                "Hard Charger",
+                "Guardian's Ring",
+                "Vanguard Ring",
+                "Guardian Axe",
+                "Blizzard"
+            ],
+            hits("ard")
+        );
+    }
+
+    #[test]
+    fn name_matches_outrank_description_matches() {
+        let named = Entry {
+            data_type: ItemType::Trait,
+            id: 1,
+            name: "Vigor".into(),
+            ..Entry::default()
+        };
+        let described = Entry {
+            data_type: ItemType::Trait,
+            details: vec![(Field::Description, "Grants vigor.".into())],
+            id: 2,
+            name: "Endurance".into(),
+            ..Entry::default()
+        };
+        let entries = vec![named, described];
+        let index = SearchIndex::new(&entries);
+        let query = Query::new("vigor");
+
+        assert!(index.score(&entries[0], &query) > index.score(&entries[1], &query));
+    }
+
+    #[test]
+    fn empty_query_matches_everything() {
+        let entry = Entry {
+            data_type: ItemType::Ring,
+            id: 1,
+            name: "Blood Font".into(),
+            ..Entry::default()
+        };
+
+        assert!(Query::new(" - ").is_empty());
+        assert_eq!(Some(0), score(&entry, ""));
+    }
+}
@@ -0,0 +1,7 @@
+mod highlight;
+mod index;
+mod query;
+
+pub use highlight::highlight;
+pub use index::{Match, SearchIndex};
+pub use query::Query;
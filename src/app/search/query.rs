@@ -0,0 +1,262 @@
+use super::index::tokenize;
+use crate::app::data::{ItemType, Stat, World};
+use crate::app::Entry;
+
+/// Filler words dropped from longer queries such as "the ring that heals".
+const STOP_WORDS: &[&str] = &[
+    "a", "an", "and", "for", "in", "is", "it", "of", "on", "that", "the", "to", "when", "with",
+];
+
+/// Search box text split into structured filters, such as `type:ring` or
+/// `damage>100`, and normalized free words. It is parsed once per change
+/// rather than once per entry. Terms that fail to parse are reported in
+/// `errors` and otherwise ignored.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Query {
+    conditions: Vec<Condition>,
+    errors: Vec<String>,
+    words: Vec<String>,
+}
+
+impl Query {
+    pub fn new(text: &str) -> Self {
+        let mut query = Self::default();
+        let mut words = vec![];
+        for term in text.split_whitespace() {
+            match parse_term(term) {
+                Ok(Some(condition)) => query.conditions.push(condition),
+                Ok(None) => words.extend(tokenize(term)),
+                Err(error) => query.errors.push(error),
+            }
+        }
+
+        let meaningful: Vec<String> = words
+            .iter()
+            .filter(|word| !STOP_WORDS.contains(&word.as_str()))
+            .cloned()
+            .collect();
+        query.words = if meaningful.is_empty() {
+            words
+        } else {
+            meaningful
+        };
+        query
+    }
+
+    pub fn errors(&self) -> &[String] {
+        &self.errors
+    }
+
+    /// Whether an entry passes every structured filter.
+    pub fn fits(&self, entry: &Entry) -> bool {
+        self.conditions
+            .iter()
+            .all(|condition| condition.fits(entry))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.conditions.is_empty() && self.words.is_empty()
+    }
+
+    pub fn words(&self) -> &[String] {
+        &self.words
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Condition {
+    Completed(bool),
+    Stat(Stat, Comparison, f64),
+    Type(Vec<ItemType>),
+    World(Vec<World>),
+}
+
+impl Condition {
+    fn fits(&self, entry: &Entry) -> bool {
+        match self {
+            Self::Completed(completed) => entry.completed == *completed,
+            Self::Stat(stat, comparison, target) => entry
+                .stats
+                .iter()
+                .any(|(s, value)| s == stat && comparison.holds(*value, *target)),
+            Self::Type(types) => types.contains(&entry.data_type),
+            Self::World(worlds) => worlds.iter().any(|world| entry.worlds.contains(world)),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Comparison {
+    Equal,
+    Greater,
+    GreaterOrEqual,
+    Less,
+    LessOrEqual,
+}
+
+impl Comparison {
+    fn holds(self, value: f64, target: f64) -> bool {
+        match self {
+            Self::Equal => (value - target).abs() < f64::EPSILON,
+            Self::Greater => value > target,
+            Self::GreaterOrEqual => value >= target,
+            Self::Less => value < target,
+            Self::LessOrEqual => value <= target,
+        }
+    }
+}
+
+/// Reads a `key:value` filter or a `stat>number` comparison, returning
+/// `None` for plain words.
+fn parse_term(term: &str) -> Result<Option<Condition>, String> {
+    if let Some((key, value)) = term.split_once(':') {
+        return parse_filter(&key.to_lowercase(), &value.to_lowercase()).map(Some);
+    }
+
+    match term.find(&['<', '>', '='][..]) {
+        Some(index) => parse_comparison(&term[..index].to_lowercase(), &term[index..]).map(Some),
+        None => Ok(None),
+    }
+}
+
+fn parse_filter(key: &str, value: &str) -> Result<Condition, String> {
+    if value.is_empty() {
+        return Err(format!("{}: needs a value", key));
+    }
+
+    match key {
+        "is" => match value {
+            "complete" | "completed" | "done" => Ok(Condition::Completed(true)),
+            "incomplete" | "missing" | "todo" => Ok(Condition::Completed(false)),
+            _ => Err(format!("unknown status: {}", value)),
+        },
+        "type" => parse_list(value, ItemType::from_param).map(Condition::Type),
+        "world" => parse_list(value, World::from_param).map(Condition::World),
+        _ => Err(format!("unknown filter: {}", key)),
+    }
+}
+
+fn parse_list<T>(value: &str, parse: fn(&str) -> Result<T, String>) -> Result<Vec<T>, String> {
+    value.split(',').map(parse).collect()
+}
+
+fn parse_comparison(name: &str, rest: &str) -> Result<Condition, String> {
+    let (comparison, number) = if let Some(number) = rest.strip_prefix(">=") {
+        (Comparison::GreaterOrEqual, number)
+    } else if let Some(number) = rest.strip_prefix("<=") {
+        (Comparison::LessOrEqual, number)
+    } else if let Some(number) = rest.strip_prefix('>') {
+        (Comparison::Greater, number)
+    } else if let Some(number) = rest.strip_prefix('<') {
+        (Comparison::Less, number)
+    } else {
+        (Comparison::Equal, rest.trim_start_matches('='))
+    };
+
+    let stat = match name {
+        "damage" | "dmg" => Stat::BaseDamage,
+        _ => Stat::from_param(name)?,
+    };
+    let target = number
+        .parse()
+        .map_err(|_| format!("{}: expected a number, got \"{}\"", name, number))?;
+    Ok(Condition::Stat(stat, comparison, target))
+}
+
+#[cfg(test)]
+#[allow(clippy::wildcard_imports)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filters_and_words_are_separated() {
+        let query = Query::new("type:ring world:rhom is:incomplete crit");
+
+        assert_eq!(
+            vec![
+                Condition::Type(vec![ItemType::Ring]),
+                Condition::World(vec![World::Rhom]),
+                Condition::Completed(false),
+            ],
+            query.conditions
+        );
+        assert_eq!(vec!["crit"], query.words());
+        assert!(query.errors().is_empty());
+    }
+
+    #[test]
+    fn filters_match_entries() {
+        let query = Query::new("type:ring,amulet world:rhom is:incomplete");
+
+        assert!(query.fits(&Entry {
+            data_type: ItemType::Amulet,
+            worlds: vec![World::Rhom, World::Any],
+            ..Entry::default()
+        }));
+        assert!(!query.fits(&Entry {
+            completed: true,
+            data_type: ItemType::Ring,
+            worlds: vec![World::Rhom],
+            ..Entry::default()
+        }));
+        assert!(!query.fits(&Entry {
+            data_type: ItemType::Ring,
+            worlds: vec![World::Corsus],
+            ..Entry::default()
+        }));
+        assert!(!query.fits(&Entry {
+            data_type: ItemType::Emote,
+            worlds: vec![World::Rhom],
+            ..Entry::default()
+        }));
+    }
+
+    #[test]
+    fn stat_comparisons_match_entries_with_that_stat() {
+        let query = Query::new("type:long-gun damage>100");
+        let gun = |damage: f64| Entry {
+            data_type: ItemType::LongGun,
+            stats: vec![(Stat::BaseDamage, damage)],
+            ..Entry::default()
+        };
+
+        assert!(query.fits(&gun(110.0)));
+        assert!(!query.fits(&gun(100.0)));
+        assert!(!query.fits(&Entry {
+            data_type: ItemType::LongGun,
+            ..Entry::default()
+        }));
+        assert!(Query::new("weight<=5").fits(&Entry {
+            data_type: ItemType::LegArmor,
+            stats: vec![(Stat::Weight, 5.0)],
+            ..Entry::default()
+        }));
+    }
+
+    #[test]
+    fn bad_terms_are_reported_and_ignored() {
+        let query = Query::new("type:shield colour:red world: damage>lots luck>3 ring");
+
+        assert_eq!(
+            vec![
+                "unknown item type: shield",
+                "unknown filter: colour",
+                "world: needs a value",
+                "damage: expected a number, got \"lots\"",
+                "unknown stat: luck",
+            ],
+            query.errors()
+        );
+        assert!(query.conditions.is_empty());
+        assert_eq!(vec!["ring"], query.words());
+    }
+
+    #[test]
+    fn stop_words_are_dropped_unless_nothing_else_is_left() {
+        assert_eq!(
+            Query::new("treasure hunter"),
+            Query::new("the treasure hunter")
+        );
+        assert!(!Query::new("the").is_empty());
+    }
+}
@@ -0,0 +1,221 @@
+use super::data::{ItemType, UrlParam, World};
+use super::facet::WorldFacet;
+use super::sort::drops_anywhere;
+use super::Entry;
+use serde_derive::{Deserialize, Serialize};
+use std::iter::once;
+use strum::IntoEnumIterator;
+use strum_macros::{AsRefStr, EnumIter};
+
+/// How the checklist splits its entries into collapsible sections.
+#[derive(AsRefStr, Clone, Debug, Deserialize, EnumIter, PartialEq, Serialize)]
+pub enum Grouping {
+    #[strum(serialize = "No grouping")]
+    Flat,
+    #[strum(serialize = "Item type")]
+    ItemType,
+    #[strum(serialize = "World")]
+    World,
+}
+
+impl Default for Grouping {
+    fn default() -> Self {
+        Self::Flat
+    }
+}
+
+impl Grouping {
+    pub fn from_param(str: &str) -> Result<Self, String> {
+        match str {
+            "flat" => Ok(Self::Flat),
+            "item-type" => Ok(Self::ItemType),
+            "world" => Ok(Self::World),
+            _ => Err(format!("unknown grouping: {}", str)),
+        }
+    }
+}
+
+impl UrlParam for Grouping {
+    fn url_slug(self) -> &'static str {
+        match self {
+            Self::Flat => "flat",
+            Self::ItemType => "item-type",
+            Self::World => "world",
+        }
+    }
+}
+
+/// One section of the grouped checklist.
+pub struct Group<'a> {
+    pub entries: Vec<&'a Entry>,
+    pub key: String,
+    pub label: String,
+}
+
+impl Group<'_> {
+    pub fn completed(&self) -> usize {
+        self.entries.iter().filter(|entry| entry.completed).count()
+    }
+
+    pub fn summary(&self) -> String {
+        format!("{}/{} complete", self.completed(), self.entries.len())
+    }
+}
+
+/// Splits entries into groups, keeping their order within each group and
+/// skipping empty groups. When grouping by world, an entry is listed under
+/// the first selected world it drops in, or the first specific one when no
+/// world is selected, or under "Any World".
+pub fn group<'a>(
+    grouping: &Grouping,
+    entries: &[&'a Entry],
+    worlds: &WorldFacet,
+) -> Vec<Group<'a>> {
+    let groups = match grouping {
+        Grouping::Flat => vec![Group {
+            entries: entries.to_vec(),
+            key: "all".into(),
+            label: "All items".into(),
+        }],
+        Grouping::ItemType => ItemType::iter()
+            .map(|data_type| Group {
+                entries: entries
+                    .iter()
+                    .filter(|entry| entry.data_type == data_type)
+                    .copied()
+                    .collect(),
+                key: format!("type-{}", data_type.clone().url_slug()),
+                label: data_type.to_string(),
+            })
+            .collect(),
+        Grouping::World => World::iter()
+            .skip(1)
+            .chain(once(World::Any))
+            .map(|world| Group {
+                entries: entries
+                    .iter()
+                    .filter(|entry| first_world(entry, worlds) == world)
+                    .copied()
+                    .collect(),
+                key: format!("world-{}", world.clone().url_slug()),
+                label: world.to_string(),
+            })
+            .collect(),
+    };
+    groups
+        .into_iter()
+        .filter(|group| !group.entries.is_empty())
+        .collect()
+}
+
+fn first_world(entry: &Entry, worlds: &WorldFacet) -> World {
+    if drops_anywhere(entry) {
+        return World::Any;
+    }
+
+    let mut specific = World::iter()
+        .skip(1)
+        .filter(|world| entry.worlds.contains(world));
+    let first = specific.clone().next();
+    specific
+        .find(|world| worlds.is_selected(world))
+        .or(first)
+        .unwrap_or(World::Any)
+}
+
+#[cfg(test)]
+#[allow(clippy::wildcard_imports)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn item_type_groups_skip_empty_types() {
+        let entries = vec![
+            Entry {
+                completed: true,
+                data_type: ItemType::Ring,
+                ..Entry::default()
+            },
+            Entry {
+                data_type: ItemType::Amulet,
+                ..Entry::default()
+            },
+            Entry {
+                data_type: ItemType::Ring,
+                ..Entry::default()
+            },
+        ];
+        let refs: Vec<&Entry> = entries.iter().collect();
+        let groups = group(&Grouping::ItemType, &refs, &WorldFacet::default());
+
+        assert_eq!(2, groups.len());
+        assert_eq!("Amulet", groups[0].label);
+        assert_eq!("type-ring", groups[1].key);
+        assert_eq!("1/2 complete", groups[1].summary());
+    }
+
+    #[test]
+    fn world_groups_list_entries_once_with_any_world_last() {
+        let entries = vec![
+            Entry {
+                data_type: ItemType::Ring,
+                worlds: World::iter().collect(),
+                ..Entry::default()
+            },
+            Entry {
+                data_type: ItemType::Ring,
+                worlds: vec![World::Rhom, World::Corsus, World::Any],
+                ..Entry::default()
+            },
+            Entry {
+                data_type: ItemType::Ring,
+                worlds: vec![World::Yaesha, World::Any],
+                ..Entry::default()
+            },
+        ];
+        let refs: Vec<&Entry> = entries.iter().collect();
+        let labels: Vec<String> = group(&Grouping::World, &refs, &WorldFacet::default())
+            .into_iter()
+            .map(|group| group.label)
+            .collect();
+
+        assert_eq!(vec!["Corsus", "Yaesha", "Any World"], labels);
+    }
+
+    #[test]
+    fn world_groups_prefer_the_selected_world() {
+        let entries = vec![Entry {
+            data_type: ItemType::Ring,
+            worlds: vec![World::Rhom, World::Corsus],
+            ..Entry::default()
+        }];
+        let refs: Vec<&Entry> = entries.iter().collect();
+        let groups = group(&Grouping::World, &refs, &WorldFacet::new(vec![World::Rhom]));
+
+        assert_eq!("Rhom", groups[0].label);
+    }
+
+    #[test]
+    fn flat_grouping_is_a_single_group() {
+        let entries = vec![Entry {
+            data_type: ItemType::Ring,
+            ..Entry::default()
+        }];
+        let refs: Vec<&Entry> = entries.iter().collect();
+
+        let worlds = WorldFacet::default();
+
+        assert_eq!(1, group(&Grouping::Flat, &refs, &worlds).len());
+        assert!(group(&Grouping::Flat, &[], &worlds).is_empty());
+    }
+
+    #[test]
+    fn grouping_url_slugs_round_trip() {
+        for grouping in Grouping::iter() {
+            assert_eq!(
+                Ok(grouping.clone()),
+                Grouping::from_param(grouping.clone().url_slug())
+            );
+        }
+    }
+}
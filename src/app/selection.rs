@@ -0,0 +1,86 @@
+/// Where a shift-click range starts: the entry last toggled by hand and the
+/// entries shown when it was clicked. The list is kept because toggling can
+/// hide the anchor itself, e.g. under the Active filter.
+pub struct Anchor {
+    pub id: String,
+    pub shown: Vec<String>,
+}
+
+impl Anchor {
+    /// The entries from the anchor to `id`, as they were shown when the
+    /// anchor was clicked, leaving out any no longer `visible` so a search
+    /// or filter change in between never marks entries out of sight.
+    pub fn range(&self, id: &str, visible: &[String]) -> Vec<String> {
+        range(&self.shown, &self.id, id)
+            .into_iter()
+            .filter(|shown| visible.contains(shown))
+            .collect()
+    }
+}
+
+/// The entries from `anchor` to `id` inclusive, in the order they are
+/// shown, whichever of the two comes first. Empty when either is no longer
+/// shown.
+fn range(shown: &[String], anchor: &str, id: &str) -> Vec<String> {
+    let start = shown.iter().position(|shown| shown == anchor);
+    let end = shown.iter().position(|shown| shown == id);
+
+    match (start, end) {
+        (Some(start), Some(end)) => shown[start.min(end)..=start.max(end)].to_vec(),
+        _ => vec![],
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::wildcard_imports)]
+mod tests {
+    use super::*;
+
+    fn ids(ids: &[&str]) -> Vec<String> {
+        ids.iter().map(|id| id.to_string()).collect()
+    }
+
+    #[test]
+    fn range_covers_both_ends() {
+        let shown = ids(&["a", "b", "c", "d"]);
+
+        assert_eq!(ids(&["b", "c", "d"]), range(&shown, "b", "d"));
+        assert_eq!(ids(&["b", "c", "d"]), range(&shown, "d", "b"));
+        assert_eq!(ids(&["c"]), range(&shown, "c", "c"));
+    }
+
+    #[test]
+    fn range_is_empty_when_the_anchor_is_hidden() {
+        let shown = ids(&["a", "b"]);
+
+        assert!(range(&shown, "z", "b").is_empty());
+    }
+
+    #[test]
+    fn anchor_ranges_survive_the_anchor_being_filtered_out() {
+        // "a" was completed by hand, so the Active filter now shows only
+        // "b", "c" and "d".
+        let anchor = Anchor {
+            id: "a".into(),
+            shown: ids(&["a", "b", "c", "d"]),
+        };
+
+        assert_eq!(ids(&["b", "c"]), anchor.range("c", &ids(&["b", "c", "d"])));
+    }
+
+    #[test]
+    fn ranges_started_before_a_filter_change_skip_hidden_entries() {
+        let anchor = Anchor {
+            id: "a".into(),
+            shown: ids(&["a", "b", "c", "d"]),
+        };
+
+        // A world filter chosen after clicking "a" hides "b".
+        assert_eq!(
+            ids(&["a", "c", "d"]),
+            anchor.range("d", &ids(&["a", "c", "d"]))
+        );
+        // "e" only appeared after the change, so there is no range to it.
+        assert!(anchor.range("e", &ids(&["a", "e"])).is_empty());
+    }
+}
@@ -0,0 +1,124 @@
+use super::data::World;
+use super::Filter;
+use strum::IntoEnumIterator;
+
+/// Keys and what they do, as listed in the cheat sheet.
+pub const HELP: &[(&str, &str)] = &[
+    ("/", "Focus search"),
+    ("j or ↓", "Next item"),
+    ("k or ↑", "Previous item"),
+    ("Space", "Toggle the focused item"),
+    ("1 2 3", "Show all, active or completed items"),
+    ("w", "Cycle through worlds"),
+    ("Ctrl+Z", "Undo the last progress change"),
+    ("Ctrl+Shift+Z", "Redo the last undone change"),
+    ("?", "Show or hide this help"),
+    ("Esc", "Leave search or close this help"),
+];
+
+/// A key press the checklist reacts to. Keys typed into form fields never
+/// reach here.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Shortcut {
+    CycleWorld,
+    Dismiss,
+    FocusSearch,
+    Next,
+    Previous,
+    Redo,
+    SetFilter(Filter),
+    Toggle,
+    ToggleHelp,
+    Undo,
+}
+
+impl Shortcut {
+    /// Reads a `KeyboardEvent.key` value, prefixed with "Ctrl+" and
+    /// "Shift+" when Ctrl or Cmd is held. Number keys pick filters in the
+    /// order they are shown in the footer.
+    pub fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "/" => Some(Self::FocusSearch),
+            " " => Some(Self::Toggle),
+            "?" => Some(Self::ToggleHelp),
+            "Ctrl+Shift+Z" => Some(Self::Redo),
+            "Ctrl+Z" => Some(Self::Undo),
+            "ArrowDown" | "j" => Some(Self::Next),
+            "ArrowUp" | "k" => Some(Self::Previous),
+            "Escape" => Some(Self::Dismiss),
+            "w" => Some(Self::CycleWorld),
+            _ => key
+                .parse::<usize>()
+                .ok()
+                .filter(|number| *number > 0)
+                .and_then(|number| Filter::iter().nth(number - 1))
+                .map(Self::SetFilter),
+        }
+    }
+}
+
+/// The world after the only selected one, or the first world when none or
+/// several are selected. Cycling past the last world clears the selection.
+pub fn next_world(selected: &[World]) -> Option<World> {
+    let mut worlds = World::iter().filter(|world| world != &World::Any);
+    match selected {
+        [current] => worlds.skip_while(|world| world != current).nth(1),
+        _ => worlds.next(),
+    }
+}
+
+/// The position one step from `current` in a list of `len` items, stopping
+/// at either end. Moving with nothing focused starts at the first item.
+pub fn step(current: Option<usize>, forward: bool, len: usize) -> Option<usize> {
+    if len == 0 {
+        return None;
+    }
+    Some(match current {
+        None => 0,
+        Some(index) if forward => (index + 1).min(len - 1),
+        Some(index) => index.saturating_sub(1),
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::wildcard_imports)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keys_map_to_shortcuts() {
+        assert_eq!(Some(Shortcut::FocusSearch), Shortcut::from_key("/"));
+        assert_eq!(Some(Shortcut::Next), Shortcut::from_key("ArrowDown"));
+        assert_eq!(Some(Shortcut::Previous), Shortcut::from_key("k"));
+        assert_eq!(
+            Some(Shortcut::SetFilter(Filter::Completed)),
+            Shortcut::from_key("3")
+        );
+        assert_eq!(None, Shortcut::from_key("0"));
+        assert_eq!(None, Shortcut::from_key("9"));
+        assert_eq!(None, Shortcut::from_key("x"));
+        assert_eq!(Some(Shortcut::Undo), Shortcut::from_key("Ctrl+Z"));
+        assert_eq!(Some(Shortcut::Redo), Shortcut::from_key("Ctrl+Shift+Z"));
+        assert_eq!(None, Shortcut::from_key("Ctrl+3"));
+    }
+
+    #[test]
+    fn next_world_cycles_and_then_clears() {
+        assert_eq!(Some(World::Corsus), next_world(&[]));
+        assert_eq!(Some(World::Earth), next_world(&[World::Corsus]));
+        assert_eq!(None, next_world(&[World::Yaesha]));
+        assert_eq!(
+            Some(World::Corsus),
+            next_world(&[World::Rhom, World::Yaesha])
+        );
+    }
+
+    #[test]
+    fn step_stops_at_the_ends() {
+        assert_eq!(None, step(None, true, 0));
+        assert_eq!(Some(0), step(None, false, 3));
+        assert_eq!(Some(2), step(Some(1), true, 3));
+        assert_eq!(Some(2), step(Some(2), true, 3));
+        assert_eq!(Some(0), step(Some(0), false, 3));
+    }
+}
@@ -0,0 +1,299 @@
+use super::data::{Stat, UrlParam, World};
+use super::Entry;
+use serde_derive::{Deserialize, Serialize};
+use std::cmp::{Ordering, Reverse};
+use std::fmt::Display;
+use strum::IntoEnumIterator;
+
+/// How the checklist orders its entries.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub enum SortOrder {
+    Category,
+    Name,
+    World,
+    RecentlyCompleted,
+    Stat(Stat),
+}
+
+impl Default for SortOrder {
+    fn default() -> Self {
+        Self::Category
+    }
+}
+
+impl SortOrder {
+    /// Every order the checklist offers, with one entry per numeric stat.
+    pub fn options() -> Vec<Self> {
+        let mut options = vec![
+            Self::Category,
+            Self::Name,
+            Self::World,
+            Self::RecentlyCompleted,
+        ];
+        options.extend(Stat::iter().map(Self::Stat));
+        options
+    }
+
+    pub fn from_param(str: &str) -> Result<Self, String> {
+        match str {
+            "category" => Ok(Self::Category),
+            "name" => Ok(Self::Name),
+            "world" => Ok(Self::World),
+            "recent" => Ok(Self::RecentlyCompleted),
+            _ => str
+                .strip_prefix("stat-")
+                .ok_or_else(|| format!("unknown sort order: {}", str))
+                .and_then(Stat::from_param)
+                .map(Self::Stat),
+        }
+    }
+
+    pub fn to_param(&self) -> String {
+        match self {
+            Self::Category => "category".into(),
+            Self::Name => "name".into(),
+            Self::World => "world".into(),
+            Self::RecentlyCompleted => "recent".into(),
+            Self::Stat(stat) => format!("stat-{}", stat.url_slug()),
+        }
+    }
+
+    /// Orders entries, breaking ties by name. `Category` keeps the catalog
+    /// order, which is already grouped by category and sorted by name.
+    pub fn sort<'a>(&self, mut entries: Vec<&'a Entry>) -> Vec<&'a Entry> {
+        match self {
+            Self::Category => {}
+            Self::Name => entries.sort_by_key(|entry| entry.name.clone()),
+            Self::World => {
+                entries.sort_by_key(|entry| (world_position(entry), entry.name.clone()));
+            }
+            Self::RecentlyCompleted => entries.sort_by_key(|entry| {
+                (
+                    Reverse(entry.completed_at),
+                    !entry.completed,
+                    entry.name.clone(),
+                )
+            }),
+            Self::Stat(stat) => entries.sort_by(|a, b| {
+                compare_stat(*stat, stat_value(a, *stat), stat_value(b, *stat))
+                    .then_with(|| a.name.cmp(&b.name))
+            }),
+        }
+        entries
+    }
+}
+
+impl Display for SortOrder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Category => write!(f, "Category"),
+            Self::Name => write!(f, "Name"),
+            Self::World => write!(f, "World"),
+            Self::RecentlyCompleted => write!(f, "Recently completed"),
+            Self::Stat(stat) => write!(f, "{}", stat),
+        }
+    }
+}
+
+/// The position of the first specific world an entry drops in, with entries
+/// that drop anywhere sorted last.
+pub fn world_position(entry: &Entry) -> usize {
+    if drops_anywhere(entry) {
+        return usize::MAX;
+    }
+
+    World::iter()
+        .skip(1)
+        .position(|world| entry.worlds.contains(&world))
+        .unwrap_or(usize::MAX)
+}
+
+/// Whether an entry drops in every world rather than specific ones.
+pub fn drops_anywhere(entry: &Entry) -> bool {
+    entry.worlds.len() == World::iter().count()
+}
+
+fn stat_value(entry: &Entry, stat: Stat) -> Option<f64> {
+    entry
+        .stats
+        .iter()
+        .find(|(s, _)| *s == stat)
+        .map(|(_, value)| *value)
+}
+
+/// The best values first, with entries lacking the stat at the end. Best is
+/// highest for every stat but weight, where the lightest gear comes first
+/// like the ascending name and world orders.
+fn compare_stat(stat: Stat, a: Option<f64>, b: Option<f64>) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => {
+            let ascending = a.partial_cmp(&b).unwrap_or(Ordering::Equal);
+            if stat == Stat::Weight {
+                ascending
+            } else {
+                ascending.reverse()
+            }
+        }
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::wildcard_imports)]
+mod tests {
+    use super::*;
+    use crate::app::data::ItemType;
+    use chrono::{Duration, Utc};
+
+    fn names(entries: &[&Entry]) -> Vec<String> {
+        entries.iter().map(|entry| entry.name.clone()).collect()
+    }
+
+    #[test]
+    fn category_keeps_the_catalog_order() {
+        let entries = vec![
+            Entry {
+                data_type: ItemType::Trait,
+                name: "Vigor".into(),
+                ..Entry::default()
+            },
+            Entry {
+                data_type: ItemType::Amulet,
+                name: "Amber Moonstone".into(),
+                ..Entry::default()
+            },
+        ];
+        let sorted = SortOrder::Category.sort(entries.iter().collect());
+
+        assert_eq!(vec!["Vigor", "Amber Moonstone"], names(&sorted));
+    }
+
+    #[test]
+    fn name_sorts_across_categories() {
+        let entries = vec![
+            Entry {
+                data_type: ItemType::Trait,
+                name: "Vigor".into(),
+                ..Entry::default()
+            },
+            Entry {
+                data_type: ItemType::Ring,
+                name: "Braided Thorns".into(),
+                ..Entry::default()
+            },
+            Entry {
+                data_type: ItemType::Amulet,
+                name: "Amber Moonstone".into(),
+                ..Entry::default()
+            },
+        ];
+        let sorted = SortOrder::Name.sort(entries.iter().collect());
+
+        assert_eq!(
+            vec!["Amber Moonstone", "Braided Thorns", "Vigor"],
+            names(&sorted)
+        );
+    }
+
+    #[test]
+    fn recently_completed_puts_the_newest_first() {
+        let now = Utc::now();
+        let entries = vec![
+            Entry {
+                data_type: ItemType::Ring,
+                name: "Incomplete".into(),
+                ..Entry::default()
+            },
+            Entry {
+                completed: true,
+                completed_at: Some(now - Duration::days(1)),
+                data_type: ItemType::Ring,
+                name: "Yesterday".into(),
+                ..Entry::default()
+            },
+            Entry {
+                completed: true,
+                data_type: ItemType::Ring,
+                name: "Untimed".into(),
+                ..Entry::default()
+            },
+            Entry {
+                completed: true,
+                completed_at: Some(now),
+                data_type: ItemType::Ring,
+                name: "Today".into(),
+                ..Entry::default()
+            },
+        ];
+        let sorted = SortOrder::RecentlyCompleted.sort(entries.iter().collect());
+
+        assert_eq!(
+            vec!["Today", "Yesterday", "Untimed", "Incomplete"],
+            names(&sorted)
+        );
+    }
+
+    #[test]
+    fn stats_sort_highest_first_and_missing_last() {
+        let entries = vec![
+            Entry {
+                data_type: ItemType::Ring,
+                name: "Ring".into(),
+                ..Entry::default()
+            },
+            Entry {
+                data_type: ItemType::HandGun,
+                name: "Repeater Pistol".into(),
+                stats: vec![(Stat::BaseDamage, 25.0)],
+                ..Entry::default()
+            },
+            Entry {
+                data_type: ItemType::LongGun,
+                name: "Sniper Rifle".into(),
+                stats: vec![(Stat::BaseDamage, 110.0)],
+                ..Entry::default()
+            },
+        ];
+        let sorted = SortOrder::Stat(Stat::BaseDamage).sort(entries.iter().collect());
+
+        assert_eq!(
+            vec!["Sniper Rifle", "Repeater Pistol", "Ring"],
+            names(&sorted)
+        );
+    }
+
+    #[test]
+    fn weight_sorts_lightest_first_and_missing_last() {
+        let armor = |name: &str, weight: f64| Entry {
+            data_type: ItemType::BodyArmor,
+            name: name.into(),
+            stats: vec![(Stat::Weight, weight)],
+            ..Entry::default()
+        };
+        let entries = vec![
+            Entry {
+                name: "Ring".into(),
+                ..Entry::default()
+            },
+            armor("Leto's Armor", 20.0),
+            armor("Cultist Duster", 5.0),
+        ];
+        let sorted = SortOrder::Stat(Stat::Weight).sort(entries.iter().collect());
+
+        assert_eq!(
+            vec!["Cultist Duster", "Leto's Armor", "Ring"],
+            names(&sorted)
+        );
+    }
+
+    #[test]
+    fn sort_order_params_round_trip() {
+        for order in SortOrder::options() {
+            assert_eq!(Ok(order.clone()), SortOrder::from_param(&order.to_param()));
+        }
+        assert!(SortOrder::from_param("stat-luck").is_err());
+        assert!(SortOrder::from_param("price").is_err());
+    }
+}
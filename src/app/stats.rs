@@ -0,0 +1,186 @@
+use super::data::{ItemType, World};
+use super::facet::remaining_by_world;
+use super::sort::drops_anywhere;
+use super::Entry;
+use std::cmp::Reverse;
+use strum::IntoEnumIterator;
+
+/// Completed out of total for some slice of the catalog.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Progress {
+    pub completed: usize,
+    pub total: usize,
+}
+
+impl Progress {
+    pub fn new<'a>(entries: impl Iterator<Item = &'a Entry>) -> Self {
+        entries.fold(Self::default(), |progress, entry| Self {
+            completed: progress.completed + usize::from(entry.completed),
+            total: progress.total + 1,
+        })
+    }
+
+    /// Whole percent complete, rounded down so 100 means truly done.
+    pub fn percent(&self) -> usize {
+        (self.completed * 100).checked_div(self.total).unwrap_or(0)
+    }
+
+    pub const fn remaining(&self) -> usize {
+        self.total - self.completed
+    }
+}
+
+pub fn by_type<'a>(entries: impl Iterator<Item = &'a Entry>) -> Vec<(ItemType, Progress)> {
+    let entries: Vec<&Entry> = entries.collect();
+    ItemType::iter()
+        .map(|data_type| {
+            let progress =
+                Progress::new(entries.iter().copied().filter(|e| e.data_type == data_type));
+            (data_type, progress)
+        })
+        .filter(|(_, progress)| progress.total > 0)
+        .collect()
+}
+
+/// Progress for each specific world, counting every entry that drops there.
+pub fn by_world<'a>(entries: impl Iterator<Item = &'a Entry>) -> Vec<(World, Progress)> {
+    let entries: Vec<&Entry> = entries.collect();
+    World::iter()
+        .filter(|world| world != &World::Any)
+        .map(|world| {
+            let progress = Progress::new(
+                entries
+                    .iter()
+                    .copied()
+                    .filter(|e| e.worlds.contains(&world)),
+            );
+            (world, progress)
+        })
+        .collect()
+}
+
+/// Progress for entries that drop anywhere against those tied to specific
+/// worlds.
+pub fn any_world_split<'a>(
+    entries: impl Iterator<Item = &'a Entry> + Clone,
+) -> (Progress, Progress) {
+    (
+        Progress::new(entries.clone().filter(|e| drops_anywhere(e))),
+        Progress::new(entries.filter(|e| !drops_anywhere(e))),
+    )
+}
+
+/// The worlds with the most incomplete entries, largest first.
+pub fn most_remaining<'a>(
+    entries: impl Iterator<Item = &'a Entry>,
+    limit: usize,
+) -> Vec<(World, usize)> {
+    let mut remaining = remaining_by_world(entries);
+    remaining.retain(|(_, count)| *count > 0);
+    remaining.sort_by_key(|(_, count)| Reverse(*count));
+    remaining.truncate(limit);
+    remaining
+}
+
+#[cfg(test)]
+#[allow(clippy::wildcard_imports)]
+mod tests {
+    use super::*;
+
+    fn entries() -> Vec<Entry> {
+        vec![
+            Entry {
+                completed: true,
+                data_type: ItemType::Ring,
+                worlds: vec![World::Rhom, World::Any],
+                ..Entry::default()
+            },
+            Entry {
+                data_type: ItemType::Ring,
+                worlds: vec![World::Rhom, World::Any],
+                ..Entry::default()
+            },
+            Entry {
+                data_type: ItemType::Amulet,
+                worlds: vec![World::Corsus, World::Any],
+                ..Entry::default()
+            },
+            Entry {
+                completed: true,
+                data_type: ItemType::Amulet,
+                worlds: World::iter().collect(),
+                ..Entry::default()
+            },
+        ]
+    }
+
+    #[test]
+    fn percent_rounds_down_and_handles_empty_progress() {
+        assert_eq!(0, Progress::default().percent());
+        assert_eq!(
+            66,
+            Progress {
+                completed: 2,
+                total: 3
+            }
+            .percent()
+        );
+        assert_eq!(50, Progress::new(entries().iter()).percent());
+    }
+
+    #[test]
+    fn by_type_skips_types_without_entries() {
+        let progress = by_type(entries().iter());
+
+        assert_eq!(2, progress.len());
+        assert_eq!(
+            (
+                ItemType::Amulet,
+                Progress {
+                    completed: 1,
+                    total: 2
+                }
+            ),
+            progress[0]
+        );
+    }
+
+    #[test]
+    fn by_world_counts_entries_in_every_world_they_drop_in() {
+        let progress = by_world(entries().iter());
+        let rhom = progress.iter().find(|(world, _)| world == &World::Rhom);
+
+        assert_eq!(World::iter().count() - 1, progress.len());
+        assert_eq!(
+            Some(&(
+                World::Rhom,
+                Progress {
+                    completed: 2,
+                    total: 3
+                }
+            )),
+            rhom
+        );
+    }
+
+    #[test]
+    fn any_world_split_separates_entries_that_drop_anywhere() {
+        let (anywhere, specific) = any_world_split(entries().iter());
+
+        assert_eq!((1, 1), (anywhere.completed, anywhere.total));
+        assert_eq!((1, 3), (specific.completed, specific.total));
+    }
+
+    #[test]
+    fn most_remaining_is_sorted_and_limited() {
+        let mut entries = entries();
+        entries.push(Entry {
+            data_type: ItemType::Ring,
+            worlds: vec![World::Rhom, World::Any],
+            ..Entry::default()
+        });
+        let remaining = most_remaining(entries.iter(), 1);
+
+        assert_eq!(vec![(World::Rhom, 2)], remaining);
+    }
+}
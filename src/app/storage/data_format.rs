@@ -1,7 +1,8 @@
-use crate::app::data::ItemType;
+use crate::app::data::{ItemType, UrlParam};
 use crate::app::Entry;
 use chrono::{DateTime, Utc};
 use serde_derive::{Deserialize, Serialize};
+use yew::format::{Json, Text};
 
 const DATA_FORMAT_VERSION: usize = 1;
 
@@ -28,11 +29,43 @@ impl DataFormat {
             ..Self::default()
         }
     }
+
+    /// Parses a progress payload previously produced by `to_json`.
+    pub fn from_json(json: String) -> Result<Self, String> {
+        let Json(data) = Json::from(Ok(json));
+        data.map_err(|error| format!("invalid progress file: {}", error))
+    }
+
+    pub fn to_json(&self) -> String {
+        let text: Text = Json(self).into();
+        text.unwrap_or_default()
+    }
+
+    /// Parses the compact `amulet-1,ring-4` form used in share links,
+    /// skipping any ids that are not recognized.
+    pub fn from_share_param(param: &str) -> Self {
+        Self {
+            completed_items: param
+                .split(',')
+                .filter_map(|id| Item::from_param(id.trim()).ok())
+                .collect(),
+            ..Self::default()
+        }
+    }
+
+    pub fn to_share_param(&self) -> String {
+        self.completed_items
+            .iter()
+            .map(|item| format!("{}-{}", item.data_type.clone().url_slug(), item.id))
+            .collect::<Vec<String>>()
+            .join(",")
+    }
 }
 
 impl From<&Entry> for Item {
     fn from(entry: &Entry) -> Self {
         Self {
+            completed_at: entry.completed_at,
             data_type: entry.data_type.clone(),
             id: entry.id,
         }
@@ -49,12 +82,42 @@ impl Default for DataFormat {
     }
 }
 
-#[derive(Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Item {
+    /// When the item was checked off. Older payloads and share links do
+    /// not carry this.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<DateTime<Utc>>,
     pub data_type: ItemType,
     pub id: u32,
 }
 
+impl Item {
+    pub fn from_param(param: &str) -> Result<Self, String> {
+        let mut parts = param.rsplitn(2, '-');
+        let id = parts
+            .next()
+            .and_then(|id| id.parse().ok())
+            .ok_or_else(|| format!("invalid item: {}", param))?;
+        let data_type = ItemType::from_param(parts.next().unwrap_or_default())?;
+        Ok(Self {
+            completed_at: None,
+            data_type,
+            id,
+        })
+    }
+
+    pub fn matches(&self, entry: &Entry) -> bool {
+        self.id == entry.id && self.data_type == entry.data_type
+    }
+
+    /// Whether both refer to the same catalog item, regardless of when
+    /// each was completed.
+    pub fn is_same_item(&self, other: &Self) -> bool {
+        self.id == other.id && self.data_type == other.data_type
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::wildcard_imports)]
 mod tests {
@@ -81,27 +144,105 @@ mod tests {
         assert_eq!(42, data.version);
     }
 
+    #[test]
+    fn test_json_round_trip() {
+        let data = DataFormat {
+            completed_items: vec![Item {
+                completed_at: None,
+                data_type: ItemType::Ring,
+                id: 4,
+            }],
+            ..DataFormat::default()
+        };
+        let restored = DataFormat::from_json(data.to_json()).unwrap();
+
+        assert_eq!(data.completed_items, restored.completed_items);
+        assert_eq!(data.last_saved_at, restored.last_saved_at);
+    }
+
+    #[test]
+    fn test_completion_times_are_optional() {
+        let json = r#"{"completed_items":[{"data_type":"Ring","id":4}],"last_saved_at":"2020-09-01T00:00:00Z","version":1}"#;
+        let data = DataFormat::from_json(json.into()).unwrap();
+
+        assert_eq!(None, data.completed_items[0].completed_at);
+        assert!(!data.to_json().contains("completed_at"));
+    }
+
+    #[test]
+    fn test_from_json_rejects_invalid_input() {
+        assert!(DataFormat::from_json("{\"nope\": true}".into()).is_err());
+    }
+
+    #[test]
+    fn test_share_param_round_trip() {
+        let data = DataFormat::from_share_param("amulet-1,armor-set-12,ring-4");
+
+        assert_eq!(
+            vec![
+                Item {
+                    completed_at: None,
+                    data_type: ItemType::Amulet,
+                    id: 1
+                },
+                Item {
+                    completed_at: None,
+                    data_type: ItemType::ArmorSet,
+                    id: 12
+                },
+                Item {
+                    completed_at: None,
+                    data_type: ItemType::Ring,
+                    id: 4
+                },
+            ],
+            data.completed_items
+        );
+        assert_eq!("amulet-1,armor-set-12,ring-4", data.to_share_param());
+    }
+
+    #[test]
+    fn test_share_param_skips_unknown_items() {
+        let data = DataFormat::from_share_param("shield-1,ring-x,,ring-2");
+
+        assert_eq!(
+            vec![Item {
+                completed_at: None,
+                data_type: ItemType::Ring,
+                id: 2
+            }],
+            data.completed_items
+        );
+    }
+
     mod new {
         use super::*;
 
-        fn build_entry(completed: bool, data_type: ItemType, id: u32) -> Entry {
-            Entry {
-                completed,
-                data_type,
-                id,
-                name: "Necklace".into(),
-                url: "https://example.com".into(),
-                worlds: vec![],
-            }
-        }
-
         #[test]
         fn test_new_converts_completd_entries_into_items() {
             let entries = vec![
-                build_entry(true, ItemType::Amulet, 4),
-                build_entry(false, ItemType::LegArmor, 5),
-                build_entry(true, ItemType::Ring, 6),
-                build_entry(false, ItemType::HeadArmor, 7),
+                Entry {
+                    completed: true,
+                    data_type: ItemType::Amulet,
+                    id: 4,
+                    ..Entry::default()
+                },
+                Entry {
+                    data_type: ItemType::LegArmor,
+                    id: 5,
+                    ..Entry::default()
+                },
+                Entry {
+                    completed: true,
+                    data_type: ItemType::Ring,
+                    id: 6,
+                    ..Entry::default()
+                },
+                Entry {
+                    data_type: ItemType::HeadArmor,
+                    id: 7,
+                    ..Entry::default()
+                },
             ];
             let completed_items = DataFormat::new(&entries).completed_items;
 
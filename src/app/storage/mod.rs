@@ -1,5 +1,10 @@
 mod data_format;
+mod preferences;
+mod settings;
 mod storage_service;
 
+pub use data_format::DataFormat;
 pub use data_format::Item as CompletedItem;
+pub use preferences::Preferences;
+pub use settings::{DefaultView, Settings, WikiProvider};
 pub use storage_service::StorageService;
@@ -0,0 +1,85 @@
+use crate::app::facet::{ItemTypeFacet, WorldFacet};
+use crate::app::sections::Grouping;
+use crate::app::sort::SortOrder;
+use crate::app::{Filter, Page};
+use serde_derive::{Deserialize, Serialize};
+
+const PREFERENCES_VERSION: usize = 1;
+
+/// UI choices remembered between visits. These are stored apart from
+/// `DataFormat` so they never end up in exported progress, and share links
+/// are built without them.
+#[derive(Deserialize, Serialize)]
+#[serde(default)]
+pub struct Preferences {
+    /// Keys of the checklist groups the player has folded away.
+    pub collapsed: Vec<String>,
+    pub filter: Filter,
+    pub grouping: Grouping,
+    pub item_types: ItemTypeFacet,
+    pub page: Page,
+    pub sort: SortOrder,
+    version: usize,
+    pub worlds: WorldFacet,
+}
+
+impl Default for Preferences {
+    fn default() -> Self {
+        Self {
+            collapsed: vec![],
+            filter: Filter::default(),
+            grouping: Grouping::default(),
+            item_types: ItemTypeFacet::default(),
+            page: Page::Checklist,
+            sort: SortOrder::default(),
+            version: PREFERENCES_VERSION,
+            worlds: WorldFacet::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::wildcard_imports)]
+mod tests {
+    use super::*;
+    use crate::app::data::World;
+    use yew::format::Json;
+
+    #[test]
+    fn test_preferences_defaults() {
+        let preferences = Preferences::default();
+
+        assert!(preferences.collapsed.is_empty());
+        assert_eq!(Filter::Active, preferences.filter);
+        assert_eq!(Grouping::Flat, preferences.grouping);
+        assert!(preferences.item_types.is_empty());
+        assert_eq!(Page::Checklist, preferences.page);
+        assert_eq!(SortOrder::Category, preferences.sort);
+        assert_eq!(PREFERENCES_VERSION, preferences.version);
+    }
+
+    #[test]
+    fn test_missing_fields_use_defaults() {
+        let Json(preferences): Json<Result<Preferences, _>> = Json::from(Ok("{}".to_string()));
+        let preferences = preferences.unwrap();
+
+        assert!(preferences.item_types.is_empty());
+        assert_eq!(PREFERENCES_VERSION, preferences.version);
+        assert!(preferences.worlds.is_empty());
+    }
+
+    #[test]
+    fn test_last_view_round_trips() {
+        let mut preferences = Preferences::default();
+        preferences.filter = Filter::Completed;
+        preferences.page = Page::Stats;
+        preferences.worlds.toggle(World::Rhom);
+        let json: yew::format::Text = Json(&preferences).into();
+        let Json(restored): Json<Result<Preferences, _>> = Json::from(json);
+        let restored = restored.unwrap();
+
+        assert_eq!(Filter::Completed, restored.filter);
+        assert_eq!(Page::Stats, restored.page);
+        assert_eq!(vec![World::Rhom], restored.worlds.selected());
+    }
+}
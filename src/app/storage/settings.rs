@@ -0,0 +1,261 @@
+use crate::app::data::{UrlParam, World};
+use crate::app::theme::Theme;
+use crate::app::{Entry, Page};
+use serde_derive::{Deserialize, Serialize};
+use strum_macros::{AsRefStr, EnumIter};
+
+const SETTINGS_VERSION: usize = 1;
+
+/// Choices made on the settings page. Unlike `Preferences`, which follow
+/// the player around the checklist, these only change when edited there.
+/// They are stored under their own key and versioned separately.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(default)]
+pub struct Settings {
+    /// Whether usage goals and page views are sent to Fathom.
+    pub analytics: bool,
+    pub default_view: DefaultView,
+    /// Hides the descriptions and locations of items not yet found.
+    pub hide_spoilers: bool,
+    /// Whether the Subject 2923 DLC is owned. Without it, items that only
+    /// drop in its worlds are left out of the checklist.
+    pub subject_2923: bool,
+    pub theme: Theme,
+    version: usize,
+    pub wiki: WikiProvider,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            analytics: true,
+            default_view: DefaultView::default(),
+            hide_spoilers: false,
+            subject_2923: true,
+            theme: Theme::default(),
+            version: SETTINGS_VERSION,
+            wiki: WikiProvider::default(),
+        }
+    }
+}
+
+impl Settings {
+    /// Whether an entry can be obtained with the DLC that is owned. Every
+    /// entry lists `World::Any`, so only the specific worlds decide.
+    pub fn owns(&self, entry: &Entry) -> bool {
+        let specific: Vec<&World> = entry
+            .worlds
+            .iter()
+            .filter(|world| **world != World::Any)
+            .collect();
+
+        self.subject_2923
+            || specific.is_empty()
+            || specific.iter().any(|world| !is_subject_2923(world))
+    }
+
+    /// Whether an entry's descriptive text may be shown.
+    pub const fn reveals(&self, entry: &Entry) -> bool {
+        !self.hide_spoilers || entry.completed
+    }
+}
+
+const fn is_subject_2923(world: &World) -> bool {
+    matches!(world, World::Reisum | World::WardPrime)
+}
+
+/// What a visit without a link in the address opens on.
+#[derive(AsRefStr, Clone, Debug, Deserialize, EnumIter, PartialEq, Serialize)]
+pub enum DefaultView {
+    #[strum(serialize = "Last used view")]
+    LastUsed,
+    #[strum(serialize = "Active items")]
+    Checklist,
+    #[strum(serialize = "Statistics")]
+    Stats,
+    #[strum(serialize = "History")]
+    History,
+}
+
+impl Default for DefaultView {
+    fn default() -> Self {
+        Self::LastUsed
+    }
+}
+
+impl DefaultView {
+    pub fn from_param(str: &str) -> Result<Self, String> {
+        match str {
+            "checklist" => Ok(Self::Checklist),
+            "history" => Ok(Self::History),
+            "last-used" => Ok(Self::LastUsed),
+            "stats" => Ok(Self::Stats),
+            _ => Err(format!("unknown default view: {}", str)),
+        }
+    }
+
+    /// The page to open on, or `None` to keep the last used view.
+    pub const fn page(&self) -> Option<Page> {
+        match self {
+            Self::LastUsed => None,
+            Self::Checklist => Some(Page::Checklist),
+            Self::Stats => Some(Page::Stats),
+            Self::History => Some(Page::History),
+        }
+    }
+}
+
+impl UrlParam for DefaultView {
+    fn url_slug(self) -> &'static str {
+        match self {
+            Self::Checklist => "checklist",
+            Self::History => "history",
+            Self::LastUsed => "last-used",
+            Self::Stats => "stats",
+        }
+    }
+}
+
+/// The wiki that entry links point to.
+#[derive(AsRefStr, Clone, Debug, Deserialize, EnumIter, PartialEq, Serialize)]
+pub enum WikiProvider {
+    #[strum(serialize = "Fextralife")]
+    Fextralife,
+    #[strum(serialize = "Fandom")]
+    Fandom,
+}
+
+impl Default for WikiProvider {
+    fn default() -> Self {
+        Self::Fextralife
+    }
+}
+
+impl WikiProvider {
+    pub fn from_param(str: &str) -> Result<Self, String> {
+        match str {
+            "fandom" => Ok(Self::Fandom),
+            "fextralife" => Ok(Self::Fextralife),
+            _ => Err(format!("unknown wiki: {}", str)),
+        }
+    }
+
+    /// The entry's page on this wiki. The catalog links to Fextralife, so
+    /// other wikis are addressed by item name.
+    pub fn url(&self, entry: &Entry) -> String {
+        match self {
+            Self::Fextralife => entry.url.clone(),
+            Self::Fandom => format!(
+                "https://remnantfromtheashes.fandom.com/wiki/{}",
+                entry.name.replace(' ', "_")
+            ),
+        }
+    }
+}
+
+impl UrlParam for WikiProvider {
+    fn url_slug(self) -> &'static str {
+        match self {
+            Self::Fandom => "fandom",
+            Self::Fextralife => "fextralife",
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::wildcard_imports)]
+mod tests {
+    use super::*;
+    use strum::IntoEnumIterator;
+    use yew::format::Json;
+
+    #[test]
+    fn test_settings_defaults() {
+        let settings = Settings::default();
+
+        assert!(settings.analytics);
+        assert_eq!(DefaultView::LastUsed, settings.default_view);
+        assert!(!settings.hide_spoilers);
+        assert!(settings.subject_2923);
+        assert_eq!(Theme::System, settings.theme);
+        assert_eq!(SETTINGS_VERSION, settings.version);
+        assert_eq!(WikiProvider::Fextralife, settings.wiki);
+    }
+
+    #[test]
+    fn test_missing_fields_use_defaults() {
+        let Json(settings): Json<Result<Settings, _>> =
+            Json::from(Ok("{\"analytics\":false}".to_string()));
+        let settings = settings.unwrap();
+
+        assert!(!settings.analytics);
+        assert!(settings.subject_2923);
+        assert_eq!(SETTINGS_VERSION, settings.version);
+    }
+
+    #[test]
+    fn test_dlc_only_items_need_the_dlc() {
+        let settings = Settings {
+            subject_2923: false,
+            ..Settings::default()
+        };
+
+        let dlc_only = Entry {
+            worlds: vec![World::Reisum, World::Any],
+            ..Entry::default()
+        };
+        assert!(!settings.owns(&dlc_only));
+        assert!(settings.owns(&Entry {
+            worlds: vec![World::Earth, World::Any],
+            ..Entry::default()
+        }));
+        assert!(settings.owns(&Entry {
+            worlds: vec![World::Reisum, World::Rhom, World::Any],
+            ..Entry::default()
+        }));
+        assert!(settings.owns(&Entry {
+            worlds: vec![World::Any],
+            ..Entry::default()
+        }));
+        assert!(Settings::default().owns(&dlc_only));
+    }
+
+    #[test]
+    fn test_spoilers_are_hidden_until_found() {
+        let settings = Settings {
+            hide_spoilers: true,
+            ..Settings::default()
+        };
+
+        assert!(!settings.reveals(&Entry::default()));
+        assert!(settings.reveals(&Entry {
+            completed: true,
+            ..Entry::default()
+        }));
+    }
+
+    #[test]
+    fn test_wiki_urls() {
+        let entry = Entry {
+            name: "Hunter's Band".into(),
+            url: "https://remnantfromtheashes.wiki.fextralife.com/Hunter's+Band".into(),
+            ..Entry::default()
+        };
+
+        assert_eq!(entry.url, WikiProvider::Fextralife.url(&entry));
+        assert_eq!(
+            "https://remnantfromtheashes.fandom.com/wiki/Hunter's_Band",
+            WikiProvider::Fandom.url(&entry)
+        );
+    }
+
+    #[test]
+    fn test_params_round_trip() {
+        for view in DefaultView::iter() {
+            assert_eq!(Ok(view.clone()), DefaultView::from_param(view.url_slug()));
+        }
+        for wiki in WikiProvider::iter() {
+            assert_eq!(Ok(wiki.clone()), WikiProvider::from_param(wiki.url_slug()));
+        }
+    }
+}
@@ -1,10 +1,16 @@
 use super::data_format::DataFormat;
+use super::preferences::Preferences;
+use super::settings::Settings;
 use crate::app::data;
+use crate::app::preset::Preset;
 use crate::app::Entry;
 use yew::format::Json;
 use yew::services::storage::{Area, StorageService as YewStorageService};
 
 const KEY: &str = "dev.coffee.remnant";
+const PREFERENCES_KEY: &str = "dev.coffee.remnant.preferences";
+const PRESETS_KEY: &str = "dev.coffee.remnant.presets";
+const SETTINGS_KEY: &str = "dev.coffee.remnant.settings";
 
 pub struct StorageService {
     storage_service: YewStorageService,
@@ -20,19 +26,31 @@ impl StorageService {
     pub fn restore(&self) -> Vec<Entry> {
         let data = self.retrieve_stored_data();
 
-        let mut entries = data::remnant_trait_entries(&data.completed_items);
-        entries.append(&mut data::amulet_entries(&data.completed_items));
-        entries.append(&mut data::armor_set_entries(&data.completed_items));
-        entries.append(&mut data::head_armor_entries(&data.completed_items));
-        entries.append(&mut data::body_armor_entries(&data.completed_items));
-        entries.append(&mut data::leg_armor_entries(&data.completed_items));
-        entries.append(&mut data::emote_entries(&data.completed_items));
-        entries.append(&mut data::ring_entries(&data.completed_items));
-        entries.append(&mut data::hand_gun_entries(&data.completed_items));
-        entries.append(&mut data::long_gun_entries(&data.completed_items));
-        entries.append(&mut data::melee_weapon_entries(&data.completed_items));
-        entries.append(&mut data::mod_entries(&data.completed_items));
-        entries
+        data::entries(&data.completed_items)
+    }
+
+    pub fn restore_preferences(&self) -> Preferences {
+        if let Json(Ok(preferences)) = self.storage_service.restore(PREFERENCES_KEY) {
+            preferences
+        } else {
+            Preferences::default()
+        }
+    }
+
+    pub fn restore_presets(&self) -> Vec<Preset> {
+        if let Json(Ok(presets)) = self.storage_service.restore(PRESETS_KEY) {
+            presets
+        } else {
+            vec![]
+        }
+    }
+
+    pub fn restore_settings(&self) -> Settings {
+        if let Json(Ok(settings)) = self.storage_service.restore(SETTINGS_KEY) {
+            settings
+        } else {
+            Settings::default()
+        }
     }
 
     #[allow(clippy::ptr_arg)]
@@ -40,6 +58,19 @@ impl StorageService {
         self.storage_service
             .store(KEY, Json(&DataFormat::new(value)));
     }
+
+    pub fn store_preferences(&mut self, preferences: &Preferences) {
+        self.storage_service
+            .store(PREFERENCES_KEY, Json(preferences));
+    }
+
+    pub fn store_settings(&mut self, settings: &Settings) {
+        self.storage_service.store(SETTINGS_KEY, Json(settings));
+    }
+
+    pub fn store_presets(&mut self, presets: &[Preset]) {
+        self.storage_service.store(PRESETS_KEY, Json(&presets));
+    }
 }
 
 impl StorageService {
@@ -0,0 +1,78 @@
+use serde_derive::{Deserialize, Serialize};
+use strum_macros::{AsRefStr, EnumIter};
+
+/// The colour scheme. `System` follows the operating system's light or
+/// dark setting.
+#[derive(AsRefStr, Clone, Debug, Deserialize, EnumIter, PartialEq, Serialize)]
+pub enum Theme {
+    System,
+    Light,
+    Dark,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::System
+    }
+}
+
+impl Theme {
+    pub fn from_param(str: &str) -> Result<Self, String> {
+        match str {
+            "dark" => Ok(Self::Dark),
+            "light" => Ok(Self::Light),
+            "system" => Ok(Self::System),
+            _ => Err(format!("unknown theme: {}", str)),
+        }
+    }
+
+    /// The class set on the root element, which picks the CSS variables.
+    pub fn class(self) -> String {
+        format!("theme-{}", self.slug())
+    }
+
+    /// The name used in the settings form and in `class`.
+    pub const fn slug(&self) -> &'static str {
+        match self {
+            Self::Dark => "dark",
+            Self::Light => "light",
+            Self::System => "system",
+        }
+    }
+
+    /// The theme the footer toggle switches to next.
+    pub const fn next(&self) -> Self {
+        match self {
+            Self::System => Self::Light,
+            Self::Light => Self::Dark,
+            Self::Dark => Self::System,
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::wildcard_imports)]
+mod tests {
+    use super::*;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn themes_round_trip_through_slugs() {
+        for theme in Theme::iter() {
+            assert_eq!(Ok(theme.clone()), Theme::from_param(theme.slug()));
+        }
+    }
+
+    #[test]
+    fn next_cycles_through_every_theme() {
+        assert_eq!(Theme::Light, Theme::System.next());
+        assert_eq!(Theme::Dark, Theme::Light.next());
+        assert_eq!(Theme::System, Theme::Dark.next());
+    }
+
+    #[test]
+    fn class_names_the_theme() {
+        assert_eq!("theme-dark", Theme::Dark.class());
+        assert_eq!("theme-system", Theme::System.class());
+    }
+}
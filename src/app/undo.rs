@@ -0,0 +1,134 @@
+use super::a11y::state_word;
+use chrono::{DateTime, Utc};
+use std::collections::VecDeque;
+
+/// How many progress changes can be undone.
+pub const UNDO_LIMIT: usize = 50;
+
+/// An entry's progress at one point in time.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Mark {
+    pub completed: bool,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+/// One entry's progress either side of a change.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Edit {
+    pub after: Mark,
+    pub before: Mark,
+    pub id: String,
+}
+
+/// Everything a single action did to progress, e.g. one toggle or one bulk
+/// change, so it is undone in one step.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Change {
+    /// What happened, e.g. "12 items marked complete".
+    pub description: String,
+    pub edits: Vec<Edit>,
+}
+
+/// Progress changes that can be undone and, once undone, redone. Making a
+/// new change forgets whatever had been undone. Only toggles and bulk marks
+/// are recorded: the checklist has no progress import or reset to undo.
+#[derive(Debug, Default)]
+pub struct UndoStack {
+    done: VecDeque<Change>,
+    undone: Vec<Change>,
+}
+
+impl UndoStack {
+    /// The change that `undo` would revert.
+    pub fn last(&self) -> Option<&Change> {
+        self.done.back()
+    }
+
+    pub fn push(&mut self, change: Change) {
+        self.undone.clear();
+        self.done.push_back(change);
+        if self.done.len() > UNDO_LIMIT {
+            self.done.pop_front();
+        }
+    }
+
+    /// Moves the last change onto the redo side, returning it so its
+    /// `before` marks can be restored.
+    pub fn undo(&mut self) -> Option<&Change> {
+        let change = self.done.pop_back()?;
+        self.undone.push(change);
+        self.undone.last()
+    }
+
+    /// Moves the last undone change back, returning it so its `after`
+    /// marks can be restored.
+    pub fn redo(&mut self) -> Option<&Change> {
+        let change = self.undone.pop()?;
+        self.done.push_back(change);
+        self.done.back()
+    }
+}
+
+/// Describes a change for the undo toast, e.g. "Akari Mask marked complete"
+/// or "12 items marked incomplete".
+pub fn describe(subject: &str, completed: bool) -> String {
+    format!("{} marked {}", subject, state_word(completed))
+}
+
+#[cfg(test)]
+#[allow(clippy::wildcard_imports)]
+mod tests {
+    use super::*;
+
+    fn change(description: &str) -> Change {
+        Change {
+            description: description.into(),
+            edits: vec![],
+        }
+    }
+
+    #[test]
+    fn changes_are_described() {
+        assert_eq!("Akari Mask marked complete", describe("Akari Mask", true));
+        assert_eq!("12 items marked incomplete", describe("12 items", false));
+    }
+
+    #[test]
+    fn undo_and_redo_walk_the_stack() {
+        let mut stack = UndoStack::default();
+        stack.push(change("first"));
+        stack.push(change("second"));
+
+        assert_eq!(Some(&change("second")), stack.undo());
+        assert_eq!(Some(&change("first")), stack.undo());
+        assert_eq!(None, stack.undo());
+        assert_eq!(Some(&change("first")), stack.redo());
+        assert_eq!(Some(&change("first")), stack.last());
+    }
+
+    #[test]
+    fn a_new_change_forgets_undone_ones() {
+        let mut stack = UndoStack::default();
+        stack.push(change("first"));
+        stack.undo();
+        stack.push(change("second"));
+
+        assert_eq!(None, stack.redo());
+    }
+
+    #[test]
+    fn the_oldest_change_is_dropped_past_the_limit() {
+        let mut stack = UndoStack::default();
+        for index in 0..=UNDO_LIMIT {
+            stack.push(change(&index.to_string()));
+        }
+
+        let mut undone = vec![];
+        while let Some(change) = stack.undo() {
+            undone.push(change.description.clone());
+        }
+        assert_eq!(UNDO_LIMIT, undone.len());
+        assert_eq!(Some(&"1".to_string()), undone.last());
+        assert!(!undone.contains(&"0".to_string()));
+    }
+}
@@ -0,0 +1,116 @@
+/// The height of the shortest checklist row in pixels. Rows are held to at
+/// least this height in CSS, so estimating with it never leaves a gap.
+pub const ROW_HEIGHT: usize = 50;
+
+/// Rows rendered beyond each edge of the viewport so fast scrolling does
+/// not reveal blank space before the next render.
+const BUFFER: usize = 10;
+
+/// The scroll position and height of the checklist's scrolling container.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Viewport {
+    pub height: usize,
+    pub top: usize,
+}
+
+impl Viewport {
+    /// Whether moving to `other` changes which rows are on screen.
+    pub const fn shows_other_rows(self, other: Self) -> bool {
+        self.height != other.height || self.top / ROW_HEIGHT != other.top / ROW_HEIGHT
+    }
+}
+
+/// The slice of a long list worth rendering, plus the padding in pixels
+/// that stands in for the rows left out above and below it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Window {
+    pub after: usize,
+    pub before: usize,
+    pub end: usize,
+    pub start: usize,
+}
+
+impl Window {
+    pub fn new(viewport: Viewport, len: usize) -> Self {
+        let first = viewport.top / ROW_HEIGHT;
+        let rows = viewport.height / ROW_HEIGHT + 1;
+        let start = first.saturating_sub(BUFFER).min(len);
+        let end = (first + rows + BUFFER).min(len);
+
+        Self {
+            after: (len - end) * ROW_HEIGHT,
+            before: start * ROW_HEIGHT,
+            end,
+            start,
+        }
+    }
+
+    /// The scroll offset that brings row `index` to the top.
+    pub const fn offset(index: usize) -> usize {
+        index * ROW_HEIGHT
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::wildcard_imports)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn window_covers_the_viewport_and_a_buffer() {
+        let window = Window::new(
+            Viewport {
+                height: 500,
+                top: 1000,
+            },
+            300,
+        );
+
+        assert_eq!(10, window.start);
+        assert_eq!(41, window.end);
+        assert_eq!(500, window.before);
+        assert_eq!(259 * ROW_HEIGHT, window.after);
+    }
+
+    #[test]
+    fn window_is_clamped_to_the_list() {
+        let window = Window::new(
+            Viewport {
+                height: 500,
+                top: 0,
+            },
+            5,
+        );
+
+        assert_eq!(
+            Window {
+                after: 0,
+                before: 0,
+                end: 5,
+                start: 0,
+            },
+            window
+        );
+    }
+
+    #[test]
+    fn small_scrolls_within_a_row_do_not_change_the_rows() {
+        let viewport = Viewport {
+            height: 500,
+            top: 100,
+        };
+
+        assert!(!viewport.shows_other_rows(Viewport {
+            top: 120,
+            ..viewport
+        }));
+        assert!(viewport.shows_other_rows(Viewport {
+            top: 150,
+            ..viewport
+        }));
+        assert!(viewport.shows_other_rows(Viewport {
+            height: 600,
+            ..viewport
+        }));
+    }
+}
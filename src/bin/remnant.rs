@@ -0,0 +1,14 @@
+use std::env;
+use std::process;
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match remnant::cli::run(&args) {
+        Ok(output) => print!("{}", output),
+        Err(error) => {
+            eprintln!("{}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,43 @@
+function isTyping(target) {
+  if (target.tagName === "INPUT") {
+    return target.type !== "checkbox";
+  }
+  return target.tagName === "SELECT" || target.tagName === "TEXTAREA";
+}
+
+function describe(event) {
+  if (event.ctrlKey || event.metaKey) {
+    const shift = event.shiftKey ? "Shift+" : "";
+    return `Ctrl+${shift}${event.key.toUpperCase()}`;
+  }
+  return event.key;
+}
+
+export function focusElement(id) {
+  const element = document.getElementById(id);
+  if (element) {
+    element.focus();
+    element.scrollIntoView({ block: "nearest" });
+    return true;
+  }
+  return false;
+}
+
+export function onKeyDown(callback) {
+  window.addEventListener("keydown", (event) => {
+    if (event.altKey) {
+      return;
+    }
+    if (event.key === "Escape") {
+      document.activeElement.blur();
+    } else if (isTyping(event.target)) {
+      return;
+    } else if (event.key === " " && event.target.type === "checkbox") {
+      return;
+    }
+
+    if (callback(describe(event))) {
+      event.preventDefault();
+    }
+  });
+}
@@ -0,0 +1,19 @@
+export function currentHash() {
+  return window.location.hash;
+}
+
+export function onHashChange(callback) {
+  window.addEventListener("hashchange", () => callback(window.location.hash));
+}
+
+export function pushHash(hash) {
+  if (window.location.hash !== hash) {
+    window.location.hash = hash;
+  }
+}
+
+export function replaceHash(hash) {
+  if (window.location.hash !== hash) {
+    window.history.replaceState(null, "", hash);
+  }
+}
@@ -0,0 +1,19 @@
+export function onScroll(id, callback) {
+  const element = document.getElementById(id);
+  if (!element) {
+    return;
+  }
+
+  const notify = () =>
+    callback(Math.round(element.scrollTop), Math.round(element.clientHeight));
+  element.addEventListener("scroll", notify, { passive: true });
+  window.addEventListener("resize", notify);
+  notify();
+}
+
+export function scrollToOffset(id, top) {
+  const element = document.getElementById(id);
+  if (element) {
+    element.scrollTop = top;
+  }
+}
@@ -1,29 +1,38 @@
-export function markItemAsComplete() {
-  if (window.fathom) {
-    window.fathom.trackGoal("TV6TKYYX", 0);
+let trackingEnabled = true;
+
+function trackGoal(code) {
+  if (trackingEnabled && window.fathom) {
+    window.fathom.trackGoal(code, 0);
   }
 }
 
-export function markItemAsIncomplete() {
+export function setTrackingEnabled(enabled) {
+  trackingEnabled = enabled;
   if (window.fathom) {
-    window.fathom.trackGoal("GTP41B0U", 0);
+    if (enabled) {
+      window.fathom.enableTrackingForMe();
+    } else {
+      window.fathom.blockTrackingForMe();
+    }
   }
 }
 
+export function markItemAsComplete() {
+  trackGoal("TV6TKYYX");
+}
+
+export function markItemAsIncomplete() {
+  trackGoal("GTP41B0U");
+}
+
 export function shareApp() {
-  if (window.fathom) {
-    window.fathom.trackGoal("2W8ZDUAZ", 0);
-  }
+  trackGoal("2W8ZDUAZ");
 }
 
 export function visitGunfireGamesSite() {
-  if (window.fathom) {
-    window.fathom.trackGoal("NE31XTOO", 0);
-  }
+  trackGoal("NE31XTOO");
 }
 
 export function visitPersonalSite() {
-  if (window.fathom) {
-    window.fathom.trackGoal("B0JCX6JN", 0);
-  }
+  trackGoal("B0JCX6JN");
 }
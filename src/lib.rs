@@ -6,6 +6,7 @@
 
 mod app;
 
+pub use app::cli;
 use wasm_bindgen::prelude::*;
 
 // When the `wee_alloc` feature is enabled, use `wee_alloc` as the global